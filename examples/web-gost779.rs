@@ -3,29 +3,21 @@ extern crate translit;
 use translit::*;
 
 fn main() {
-    let v: CharsMapping = [
-        (" ", "-"),
-        (",", ""),
-        (":", ""),
-        (";", ""),
-        ("№", ""),
-        ("ь", ""),
-        ("ъ", ""),
-        ("Ы", "Y"),
-        ("Э", "E"),
-        ("ы", "y"),
-        ("э", "e"),
-    ]
-    .iter()
-    .cloned()
-    .collect();
-
-    let mut custom_table = gost779b_ru();
-    custom_table.retain(|&x| !x.1.contains("`"));
-    custom_table.retain(|&x| !x.1.contains("#"));
-    custom_table.extend(v.iter());
-
-    let trn = Transliterator::new(custom_table);
+    let trn = TransliteratorBuilder::from(Gost779B::new(Language::Ru))
+        .retain(|rule| !rule.translit.contains('`') && !rule.translit.contains('#'))
+        .insert(" ", "-")
+        .insert(",", "")
+        .insert(":", "")
+        .insert(";", "")
+        .insert("№", "")
+        .insert("ь", "")
+        .insert("ъ", "")
+        .insert("Ы", "Y")
+        .insert("Э", "E")
+        .insert("ы", "y")
+        .insert("э", "e")
+        .build()
+        .unwrap();
 
     let source = "Общие вопросы по языку, получение помощи".to_lowercase();
 
@@ -0,0 +1,313 @@
+use super::CharsMapping;
+
+/// The Latin strings with diacritics and their ASCII spellings,
+/// the tie "͡" (U+0361) joins two letters which stand for one Cyrillic letter.
+const MARKED: &[(&str, &str)] = &[
+    ("Ĭ", "I"),
+    ("Ė", "E"),
+    ("Ë", "E"),
+    ("Ï", "I"),
+    ("Ŭ", "U"),
+    ("Ī", "I"),
+    ("Ḟ", "F"),
+    ("Ẏ", "Y"),
+    ("T\u{361}s", "Ts"),
+    ("I\u{361}u", "Iu"),
+    ("I\u{361}a", "Ia"),
+    ("I\u{361}e", "Ie"),
+    ("I\u{361}o", "Io"),
+    ("ĭ", "i"),
+    ("ė", "e"),
+    ("ë", "e"),
+    ("ï", "i"),
+    ("ŭ", "u"),
+    ("ī", "i"),
+    ("ḟ", "f"),
+    ("ẏ", "y"),
+    ("t\u{361}s", "ts"),
+    ("i\u{361}u", "iu"),
+    ("i\u{361}a", "ia"),
+    ("i\u{361}e", "ie"),
+    ("i\u{361}o", "io"),
+    ("ʹ", "'"),
+    ("ʺ", "\""),
+];
+
+/// Cyrillic Russian transliteration table.
+/// implementation ALA-LC, the romanization of the Library of Congress (1997).
+/// more details:
+/// [ALA-LC romanization of Russian](https://en.wikipedia.org/wiki/ALA-LC_romanization_of_Russian)
+///
+/// The table includes the pre-reform letters `і`, `ѣ`, `ѳ`, `ѵ`.
+pub fn ala_lc_ru() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ё", "Ë"),
+        ("Ж", "Zh"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Й", "Ĭ"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "Kh"),
+        ("Ц", "T\u{361}s"),
+        ("Ч", "Ch"),
+        ("Ш", "Sh"),
+        ("Щ", "Shch"),
+        ("ъ", "ʺ"),
+        ("Ъ", "ʺ"),
+        ("Ы", "Y"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("Э", "Ė"),
+        ("Ю", "I\u{361}u"),
+        ("Я", "I\u{361}a"),
+        ("І", "Ī"),
+        ("Ѣ", "I\u{361}e"),
+        ("Ѳ", "Ḟ"),
+        ("Ѵ", "Ẏ"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ё", "ë"),
+        ("ж", "zh"),
+        ("з", "z"),
+        ("и", "i"),
+        ("й", "ĭ"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "kh"),
+        ("ц", "t\u{361}s"),
+        ("ч", "ch"),
+        ("ш", "sh"),
+        ("щ", "shch"),
+        ("ы", "y"),
+        ("э", "ė"),
+        ("ю", "i\u{361}u"),
+        ("я", "i\u{361}a"),
+        ("і", "ī"),
+        ("ѣ", "i\u{361}e"),
+        ("ѳ", "ḟ"),
+        ("ѵ", "ẏ"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Ukrainian transliteration table.
+/// implementation ALA-LC, the romanization of the Library of Congress (1997).
+/// more details:
+/// [Romanization of Ukrainian](https://en.wikipedia.org/wiki/Romanization_of_Ukrainian#Tables_of_romanization_systems)
+///
+/// The apostrophe is transliterated as the double prime "ʺ".
+pub fn ala_lc_ua() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "H"),
+        ("Ґ", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Є", "I\u{361}e"),
+        ("Ж", "Zh"),
+        ("З", "Z"),
+        ("И", "Y"),
+        ("І", "I"),
+        ("Ї", "Ï"),
+        ("Й", "Ĭ"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "Kh"),
+        ("Ц", "T\u{361}s"),
+        ("Ч", "Ch"),
+        ("Ш", "Sh"),
+        ("Щ", "Shch"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("Ю", "I\u{361}u"),
+        ("Я", "I\u{361}a"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "h"),
+        ("ґ", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("є", "i\u{361}e"),
+        ("ж", "zh"),
+        ("з", "z"),
+        ("и", "y"),
+        ("і", "i"),
+        ("ї", "ï"),
+        ("й", "ĭ"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "kh"),
+        ("ц", "t\u{361}s"),
+        ("ч", "ch"),
+        ("ш", "sh"),
+        ("щ", "shch"),
+        ("ю", "i\u{361}u"),
+        ("я", "i\u{361}a"),
+        ("'", "ʺ"),
+        ("’", "ʺ"),
+        ("ʼ", "ʺ"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Belarusian transliteration table.
+/// implementation ALA-LC, the romanization of the Library of Congress (1997).
+/// more details:
+/// [Romanization of Belarusian](https://en.wikipedia.org/wiki/Romanization_of_Belarusian)
+///
+/// The apostrophe is transliterated as the double prime "ʺ".
+pub fn ala_lc_by() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "H"),
+        ("Ґ", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ё", "I\u{361}o"),
+        ("Ж", "Zh"),
+        ("З", "Z"),
+        ("І", "I"),
+        ("Й", "Ĭ"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ў", "Ŭ"),
+        ("Ф", "F"),
+        ("Х", "Kh"),
+        ("Ц", "T\u{361}s"),
+        ("Ч", "Ch"),
+        ("Ш", "Sh"),
+        ("Ы", "Y"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("Э", "Ė"),
+        ("Ю", "I\u{361}u"),
+        ("Я", "I\u{361}a"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "h"),
+        ("ґ", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ё", "i\u{361}o"),
+        ("ж", "zh"),
+        ("з", "z"),
+        ("і", "i"),
+        ("й", "ĭ"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ў", "ŭ"),
+        ("ф", "f"),
+        ("х", "kh"),
+        ("ц", "t\u{361}s"),
+        ("ч", "ch"),
+        ("ш", "sh"),
+        ("ы", "y"),
+        ("э", "ė"),
+        ("ю", "i\u{361}u"),
+        ("я", "i\u{361}a"),
+        ("'", "ʺ"),
+        ("’", "ʺ"),
+        ("ʼ", "ʺ"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// The ALA-LC table in ASCII: the diacritics and the ties are dropped,
+/// the prime and the double prime are written as "'" and "\"".
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{ala_lc_ascii, ala_lc_ru, Transliterator};
+/// let trasliterator = Transliterator::new(ala_lc_ascii(ala_lc_ru()));
+/// assert_eq!("Iurii Gagarin", trasliterator.convert("Юрий Гагарин", false));
+///
+/// ```
+pub fn ala_lc_ascii(table: CharsMapping) -> CharsMapping {
+    table
+        .into_iter()
+        .map(|(source, translit)| {
+            let ascii = MARKED
+                .iter()
+                .find(|&&(marked, _)| marked == translit)
+                .map_or(translit, |&(_, ascii)| ascii);
+            (source, ascii)
+        })
+        .collect()
+}
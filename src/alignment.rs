@@ -0,0 +1,133 @@
+use std::ops::Range;
+
+use crate::transliterator::{Step, Transliterator};
+
+/// The output of the transliteration with the segments of the input it was produced from,
+/// see [`Transliterator::convert_with_alignment`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Alignment {
+    /// The transliterated text.
+    pub output: String,
+    /// The segments in the order of the input, one for each applied rule
+    /// and for each copied character.
+    pub segments: Vec<Segment>,
+}
+
+/// A byte range of the input and the byte range of the output produced from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// The byte range in the input.
+    pub source: Range<usize>,
+    /// The byte range in the output, empty if the rule produces nothing (like `ь` -> "").
+    pub output: Range<usize>,
+}
+
+impl Alignment {
+    /// Maps a byte range of the output to the smallest range of the input which produced it.
+    ///
+    /// Segments partially covered by the range are included entirely.
+    pub fn source_range(&self, output: Range<usize>) -> Range<usize> {
+        map_range(
+            &self.segments,
+            output,
+            |segment| &segment.output,
+            |segment| &segment.source,
+        )
+    }
+
+    /// Maps a byte range of the input to the range of the output produced from it.
+    ///
+    /// Segments partially covered by the range are included entirely.
+    pub fn output_range(&self, source: Range<usize>) -> Range<usize> {
+        map_range(
+            &self.segments,
+            source,
+            |segment| &segment.source,
+            |segment| &segment.output,
+        )
+    }
+}
+
+fn map_range<F, T>(segments: &[Segment], range: Range<usize>, from: F, to: T) -> Range<usize>
+where
+    F: Fn(&Segment) -> &Range<usize>,
+    T: Fn(&Segment) -> &Range<usize>,
+{
+    // a segment without characters (like "ь" -> "") is included if it is inside the range
+    let overlaps = |segment: &&Segment| {
+        let from = from(segment);
+        if from.is_empty() {
+            range.start < from.start && from.start < range.end
+        } else {
+            from.start < range.end && range.start < from.end
+        }
+    };
+
+    let mut mapped = segments.iter().filter(overlaps).map(&to);
+    match mapped.next() {
+        Some(first) => first.start..mapped.last().map_or(first.end, |last| last.end),
+        None => {
+            // an empty range is mapped to the position of the next segment
+            let pos = segments
+                .iter()
+                .find(|segment| from(segment).start >= range.start)
+                .or(segments.last())
+                .map_or(0, |segment| {
+                    if from(segment).start >= range.start {
+                        to(segment).start
+                    } else {
+                        to(segment).end
+                    }
+                });
+            pos..pos
+        }
+    }
+}
+
+impl Transliterator {
+    /// Transliterates input string and returns the output with the segments
+    /// of the input it was produced from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{iternational_passport_2013_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// let alignment = trasliterator.convert_with_alignment("Щука", false);
+    ///
+    /// assert_eq!("Shchuka", alignment.output);
+    /// assert_eq!(0..2, alignment.segments[0].source);
+    /// assert_eq!(0..4, alignment.segments[0].output);
+    /// // "chu" is found in the output, "Щу" is highlighted in the input
+    /// assert_eq!(0..4, alignment.source_range(2..5));
+    ///
+    /// ```
+    pub fn convert_with_alignment(&self, src: &str, invert: bool) -> Alignment {
+        let mut alignment = Alignment {
+            output: String::with_capacity(src.len()),
+            segments: Vec::new(),
+        };
+        let mut pos = 0;
+
+        while pos < src.len() {
+            let start = alignment.output.len();
+            let step = self
+                .step(src, pos, invert, &mut alignment.output)
+                .expect("writing to a `String` never fails");
+            if let Step::Char(c) = step {
+                self.write_char(c, invert, &mut alignment.output)
+                    .expect("writing to a `String` never fails");
+            }
+
+            let end = pos + step.len();
+            alignment.segments.push(Segment {
+                source: pos..end,
+                output: start..alignment.output.len(),
+            });
+            pos = end;
+        }
+
+        alignment
+    }
+}
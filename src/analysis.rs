@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use crate::casing::same_letters;
+use crate::error::RoundTripError;
+use crate::transliterator::{Rule, Transliterator};
+
+/// The result of the analysis of a transliteration table, see [`analyze`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Analysis {
+    /// Latin strings produced by several Cyrillic strings, with these Cyrillic strings.
+    ///
+    /// Strings which differ only in case, like "Ь" and "ь", are the same source.
+    pub many_to_one: Vec<(&'static str, Vec<&'static str>)>,
+    /// Cyrillic strings which are transliterated to an empty string.
+    pub empty: Vec<&'static str>,
+    /// Pairs of Latin strings where the first one is a prefix of the second one,
+    /// like "s" and "sh".
+    pub prefix_conflicts: Vec<(&'static str, &'static str)>,
+    /// A Latin string which can be read as two different Cyrillic texts,
+    /// `None` if the Latin strings of the table are uniquely decodable.
+    pub ambiguity: Option<Ambiguity>,
+}
+
+/// A Latin string with two different readings, see [`Analysis::ambiguity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity {
+    /// The Latin string.
+    pub translit: String,
+    /// The Cyrillic strings of the first reading.
+    pub first: Vec<&'static str>,
+    /// The Cyrillic strings of the second reading.
+    pub second: Vec<&'static str>,
+}
+
+impl Analysis {
+    /// Checks that any Latin text composed of the strings of the table has only one reading.
+    pub fn is_uniquely_decodable(&self) -> bool {
+        self.ambiguity.is_none()
+    }
+
+    /// Checks that the transliteration from the Latin alphabet restores the Cyrillic text:
+    /// every Latin string is not empty, has a single source and the strings are uniquely decodable.
+    pub fn is_reversible(&self) -> bool {
+        self.many_to_one.is_empty() && self.empty.is_empty() && self.is_uniquely_decodable()
+    }
+}
+
+/// Analyzes whether the transliteration table can be reversed.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{analyze, iternational_passport_2013_ru};
+/// let analysis = analyze(&iternational_passport_2013_ru());
+///
+/// assert!(!analysis.is_reversible());
+/// assert!(analysis.many_to_one.contains(&("E", vec!["Е", "Ё", "Э"])));
+/// assert!(analysis.empty.contains(&"ь"));
+/// assert!(analysis.prefix_conflicts.contains(&("sh", "shch")));
+///
+/// ```
+pub fn analyze(table: &[(&'static str, &'static str)]) -> Analysis {
+    analyze_pairs(table.iter().copied())
+}
+
+impl Transliterator {
+    /// Analyzes whether the rules of the transliterator can be reversed, see [`analyze`].
+    ///
+    /// Rules with the same source string and different Latin strings
+    /// (like `ц` -> "c" or "cz") are alternatives and not a conflict.
+    pub fn analyze(&self) -> Analysis {
+        analyze_rules(self.rules())
+    }
+}
+
+impl Transliterator {
+    /// Checks that `src` is restored by the transliteration in the Latin alphabet and back.
+    ///
+    /// See [`Gost779B`](crate::Gost779B) for the sequences which GOST 7.79 System B
+    /// does not restore.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first byte offset where the restored text differs from `src`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{gost779b_ru, iternational_passport_2013_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(gost779b_ru());
+    /// assert!(trasliterator.verify_round_trip("Объём").is_ok());
+    ///
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// let err = trasliterator.verify_round_trip("Ель").unwrap_err();
+    /// assert_eq!("El", err.translit());
+    /// assert_eq!(4, err.offset());
+    ///
+    /// ```
+    pub fn verify_round_trip(&self, src: &str) -> Result<(), RoundTripError> {
+        let translit = self.convert(src, false);
+        let restored = self.convert(&translit, true);
+        compare_round_trip(src, translit, restored)
+    }
+}
+
+/// Reports the first difference of the text restored from the transliteration.
+pub(crate) fn compare_round_trip(
+    src: &str,
+    translit: String,
+    restored: String,
+) -> Result<(), RoundTripError> {
+    if restored == src {
+        return Ok(());
+    }
+
+    let offset = src
+        .char_indices()
+        .zip(restored.chars())
+        .find(|&((_, a), b)| a != b)
+        .map_or_else(|| src.len().min(restored.len()), |((offset, _), _)| offset);
+    Err(RoundTripError::new(offset, translit, restored))
+}
+
+pub(crate) fn analyze_rules(rules: &[Rule]) -> Analysis {
+    analyze_pairs(rules.iter().map(|rule| (rule.source, rule.translit)))
+}
+
+fn analyze_pairs<I>(pairs: I) -> Analysis
+where
+    I: Iterator<Item = (&'static str, &'static str)>,
+{
+    let mut sources: BTreeMap<&'static str, Vec<&'static str>> = BTreeMap::new();
+    let mut empty = Vec::new();
+
+    for (source, translit) in pairs.filter(|(source, _)| !source.is_empty()) {
+        if translit.is_empty() {
+            if !empty.contains(&source) {
+                empty.push(source);
+            }
+            continue;
+        }
+
+        let list = sources.entry(translit).or_default();
+        if !list.iter().any(|other| same_letters(other, source)) {
+            list.push(source);
+        }
+    }
+
+    let many_to_one = sources
+        .iter()
+        .filter(|(_, list)| list.len() > 1)
+        .map(|(&translit, list)| (translit, list.clone()))
+        .collect();
+
+    let codes: Vec<(&'static str, &'static str)> = sources
+        .iter()
+        .map(|(&translit, list)| (translit, list[0]))
+        .collect();
+
+    let mut prefix_conflicts = Vec::new();
+    for &(short, _) in &codes {
+        for &(long, _) in &codes {
+            if long.len() > short.len() && long.starts_with(short) {
+                prefix_conflicts.push((short, long));
+            }
+        }
+    }
+
+    Analysis {
+        many_to_one,
+        empty,
+        prefix_conflicts,
+        ambiguity: find_ambiguity(&codes),
+    }
+}
+
+/// A partial pair of readings: the Latin text of `longer` is the text of `shorter`
+/// followed by `suffix`.
+struct Dangling {
+    suffix: String,
+    longer: Vec<usize>,
+    shorter: Vec<usize>,
+}
+
+/// The Sardinas–Patterson test which also returns the shortest found ambiguous string.
+fn find_ambiguity(codes: &[(&'static str, &'static str)]) -> Option<Ambiguity> {
+    let mut queue = VecDeque::new();
+    let mut seen = HashSet::new();
+
+    for (short, &(a, _)) in codes.iter().enumerate() {
+        for (long, &(b, _)) in codes.iter().enumerate() {
+            if b.len() > a.len() && b.starts_with(a) {
+                seen.insert(b[a.len()..].to_owned());
+                queue.push_back(Dangling {
+                    suffix: b[a.len()..].to_owned(),
+                    longer: vec![long],
+                    shorter: vec![short],
+                });
+            }
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let suffix = state.suffix.as_str();
+
+        for (index, &(code, _)) in codes.iter().enumerate() {
+            let mut shorter = state.shorter.clone();
+            shorter.push(index);
+
+            if code == suffix {
+                let first = readings(codes, &state.longer);
+                let second = readings(codes, &shorter);
+                if first.concat() != second.concat() {
+                    return Some(Ambiguity {
+                        translit: state.longer.iter().map(|&i| codes[i].0).collect(),
+                        first,
+                        second,
+                    });
+                }
+            } else if let Some(next) = suffix.strip_prefix(code) {
+                if seen.insert(next.to_owned()) {
+                    queue.push_back(Dangling {
+                        suffix: next.to_owned(),
+                        longer: state.longer.clone(),
+                        shorter,
+                    });
+                }
+            } else if let Some(next) = code.strip_prefix(suffix) {
+                if seen.insert(next.to_owned()) {
+                    queue.push_back(Dangling {
+                        suffix: next.to_owned(),
+                        longer: shorter,
+                        shorter: state.longer.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn readings(codes: &[(&'static str, &'static str)], indices: &[usize]) -> Vec<&'static str> {
+    indices.iter().map(|&i| codes[i].1).collect()
+}
@@ -0,0 +1,378 @@
+use crate::bulgarian;
+use crate::{Condition, Rule};
+
+/// BGN/PCGN romanization of Russian (1947).
+///
+/// more details:
+/// [Romanization of Russian #BGN/PCGN](https://en.wikipedia.org/wiki/BGN/PCGN_romanization_of_Russian)
+///
+/// The letters `е` and `ё` are transliterated as `ye` and `yë` at the beginning of a word,
+/// after vowels, `й`, `ъ` and `ь`. With `separator` the letters which would be read
+/// as another letter are separated by "·": `тс` -> "t·s" (not `ц`), `шч` -> "sh·ch" (not `щ`),
+/// `ыа` -> "y·a" (not `я`).
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn bgn_pcgn_ru(separator: bool) -> Vec<Rule> {
+    let word_start = Condition::WordBoundary;
+    let after_vowel = Condition::OneOf("аеёиоуыэюяйъь");
+
+    let mut rules = Vec::new();
+    if separator {
+        rules.extend(separators(&[
+            ("З", "Z·", "х"),
+            ("К", "K·", "х"),
+            ("С", "S·", "х"),
+            ("Т", "T·", "с"),
+            ("Ц", "Ts·", "х"),
+            ("Ш", "Sh·", "ч"),
+            ("Й", "Y·", "ауыэ"),
+            ("Ы", "Y·", "ауыэ"),
+            ("з", "z·", "х"),
+            ("к", "k·", "х"),
+            ("с", "s·", "х"),
+            ("т", "t·", "с"),
+            ("ц", "ts·", "х"),
+            ("ш", "sh·", "ч"),
+            ("й", "y·", "ауыэ"),
+            ("ы", "y·", "ауыэ"),
+        ]));
+    }
+
+    rules.extend([
+        Rule::new("Е", "Ye").before(word_start),
+        Rule::new("Е", "Ye").before(after_vowel),
+        Rule::new("Е", "E"),
+        Rule::new("Ё", "Yë").before(word_start),
+        Rule::new("Ё", "Yë").before(after_vowel),
+        Rule::new("Ё", "Ë"),
+        Rule::new("е", "ye").before(word_start),
+        Rule::new("е", "ye").before(after_vowel),
+        Rule::new("е", "e"),
+        Rule::new("ё", "yë").before(word_start),
+        Rule::new("ё", "yë").before(after_vowel),
+        Rule::new("ё", "ë"),
+    ]);
+
+    rules.extend(
+        [
+            ("А", "A"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "G"),
+            ("Д", "D"),
+            ("Ж", "Zh"),
+            ("З", "Z"),
+            ("И", "I"),
+            ("Й", "Y"),
+            ("К", "K"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("О", "O"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ф", "F"),
+            ("Х", "Kh"),
+            ("Ц", "Ts"),
+            ("Ч", "Ch"),
+            ("Ш", "Sh"),
+            ("Щ", "Shch"),
+            ("ъ", "”"),
+            ("Ъ", "”"),
+            ("Ы", "Y"),
+            ("ь", "’"),
+            ("Ь", "’"),
+            ("Э", "E"),
+            ("Ю", "Yu"),
+            ("Я", "Ya"),
+            ("а", "a"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "g"),
+            ("д", "d"),
+            ("ж", "zh"),
+            ("з", "z"),
+            ("и", "i"),
+            ("й", "y"),
+            ("к", "k"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("о", "o"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ф", "f"),
+            ("х", "kh"),
+            ("ц", "ts"),
+            ("ч", "ch"),
+            ("ш", "sh"),
+            ("щ", "shch"),
+            ("ы", "y"),
+            ("э", "e"),
+            ("ю", "yu"),
+            ("я", "ya"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
+
+/// BGN/PCGN romanization of Ukrainian (1965).
+///
+/// more details:
+/// [Romanization of Ukrainian](https://en.wikipedia.org/wiki/Romanization_of_Ukrainian#Tables_of_romanization_systems)
+///
+/// The soft sign is transliterated as "’", the apostrophe as "”". With `separator`
+/// the letters which would be read as another letter are separated by "·":
+/// `зг` -> "z·h" (not `ж`), `шч` -> "sh·ch" (not `щ`), `йа` -> "y·a" (not `я`).
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn bgn_pcgn_ua(separator: bool) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    if separator {
+        rules.extend(separators(&[
+            ("З", "Z·", "г"),
+            ("К", "K·", "г"),
+            ("С", "S·", "г"),
+            ("Т", "T·", "с"),
+            ("Ц", "Ts·", "г"),
+            ("Ш", "Sh·", "ч"),
+            ("Й", "Y·", "аеіу"),
+            ("з", "z·", "г"),
+            ("к", "k·", "г"),
+            ("с", "s·", "г"),
+            ("т", "t·", "с"),
+            ("ц", "ts·", "г"),
+            ("ш", "sh·", "ч"),
+            ("й", "y·", "аеіу"),
+        ]));
+    }
+
+    rules.extend(
+        [
+            ("А", "A"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "H"),
+            ("Ґ", "G"),
+            ("Д", "D"),
+            ("Е", "E"),
+            ("Є", "Ye"),
+            ("Ж", "Zh"),
+            ("З", "Z"),
+            ("И", "Y"),
+            ("І", "I"),
+            ("Ї", "Yi"),
+            ("Й", "Y"),
+            ("К", "K"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("О", "O"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ф", "F"),
+            ("Х", "Kh"),
+            ("Ц", "Ts"),
+            ("Ч", "Ch"),
+            ("Ш", "Sh"),
+            ("Щ", "Shch"),
+            ("ь", "’"),
+            ("Ь", "’"),
+            ("Ю", "Yu"),
+            ("Я", "Ya"),
+            ("а", "a"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "h"),
+            ("ґ", "g"),
+            ("д", "d"),
+            ("е", "e"),
+            ("є", "ye"),
+            ("ж", "zh"),
+            ("з", "z"),
+            ("и", "y"),
+            ("і", "i"),
+            ("ї", "yi"),
+            ("й", "y"),
+            ("к", "k"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("о", "o"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ф", "f"),
+            ("х", "kh"),
+            ("ц", "ts"),
+            ("ч", "ch"),
+            ("ш", "sh"),
+            ("щ", "shch"),
+            ("ю", "yu"),
+            ("я", "ya"),
+            ("'", "”"),
+            ("’", "”"),
+            ("ʼ", "”"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
+
+/// BGN/PCGN romanization of Belarusian (1979).
+///
+/// more details:
+/// [Romanization of Belarusian](https://en.wikipedia.org/wiki/Romanization_of_Belarusian)
+///
+/// The letter `е` is transliterated as `ye` at the beginning of a word, after vowels,
+/// `й`, `ь` and the apostrophe. The soft sign is transliterated as "’", the apostrophe as "”".
+/// With `separator` the letters which would be read as another letter are separated by "·":
+/// `зг` -> "z·h" (not `ж`), `шч` -> "sh·ch" (not `щ`), `йа` -> "y·a" (not `я`).
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn bgn_pcgn_by(separator: bool) -> Vec<Rule> {
+    let word_start = Condition::WordBoundary;
+    let after_vowel = Condition::OneOf("аеёіоуыэюяйь'’ʼ");
+
+    let mut rules = Vec::new();
+    if separator {
+        rules.extend(separators(&[
+            ("З", "Z·", "г"),
+            ("К", "K·", "г"),
+            ("С", "S·", "г"),
+            ("Т", "T·", "с"),
+            ("Ц", "Ts·", "г"),
+            ("Ш", "Sh·", "ч"),
+            ("Й", "Y·", "аоуыэ"),
+            ("Ы", "Y·", "аоуыэ"),
+            ("з", "z·", "г"),
+            ("к", "k·", "г"),
+            ("с", "s·", "г"),
+            ("т", "t·", "с"),
+            ("ц", "ts·", "г"),
+            ("ш", "sh·", "ч"),
+            ("й", "y·", "аоуыэ"),
+            ("ы", "y·", "аоуыэ"),
+        ]));
+    }
+
+    rules.extend([
+        Rule::new("Е", "Ye").before(word_start),
+        Rule::new("Е", "Ye").before(after_vowel),
+        Rule::new("Е", "E"),
+        Rule::new("е", "ye").before(word_start),
+        Rule::new("е", "ye").before(after_vowel),
+        Rule::new("е", "e"),
+    ]);
+
+    rules.extend(
+        [
+            ("А", "A"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "H"),
+            ("Д", "D"),
+            ("Ё", "Yo"),
+            ("Ж", "Zh"),
+            ("З", "Z"),
+            ("І", "I"),
+            ("Й", "Y"),
+            ("К", "K"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("О", "O"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ў", "W"),
+            ("Ф", "F"),
+            ("Х", "Kh"),
+            ("Ц", "Ts"),
+            ("Ч", "Ch"),
+            ("Ш", "Sh"),
+            ("Ы", "Y"),
+            ("ь", "’"),
+            ("Ь", "’"),
+            ("Э", "E"),
+            ("Ю", "Yu"),
+            ("Я", "Ya"),
+            ("а", "a"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "h"),
+            ("д", "d"),
+            ("ё", "yo"),
+            ("ж", "zh"),
+            ("з", "z"),
+            ("і", "i"),
+            ("й", "y"),
+            ("к", "k"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("о", "o"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ў", "w"),
+            ("ф", "f"),
+            ("х", "kh"),
+            ("ц", "ts"),
+            ("ч", "ch"),
+            ("ш", "sh"),
+            ("ы", "y"),
+            ("э", "e"),
+            ("ю", "yu"),
+            ("я", "ya"),
+            ("'", "”"),
+            ("’", "”"),
+            ("ʼ", "”"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
+
+/// BGN/PCGN romanization of Bulgarian (2013).
+///
+/// BGN and PCGN adopted the official Streamlined System of Bulgaria,
+/// see [`streamlined_system`](crate::streamlined_system), with `ия` at the end
+/// of a word transliterated as `ia`. The system needs no separator.
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn bgn_pcgn_bg() -> Vec<Rule> {
+    let mut rules = vec![Rule::new("ия", "ia").after(Condition::WordBoundary)];
+    rules.extend(bulgarian::streamlined_system().into_iter().map(Rule::from));
+    rules
+}
+
+/// A letter followed by one of the listed letters is transliterated with the separator.
+pub(crate) fn separators(letters: &[(&'static str, &'static str, &'static str)]) -> Vec<Rule> {
+    letters
+        .iter()
+        .map(|&(source, translit, next)| Rule::new(source, translit).after(Condition::OneOf(next)))
+        .collect()
+}
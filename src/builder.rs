@@ -0,0 +1,188 @@
+use super::CharsMapping;
+
+use crate::analysis::analyze_rules;
+use crate::casing::same_letters;
+use crate::error::BuildError;
+use crate::transliterator::{Rule, Transliterator};
+use crate::unmapped::UnmappedPolicy;
+
+/// The directions in which a transliterator is going to be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Only the transliteration in the Latin alphabet.
+    #[default]
+    ToLatin,
+    /// The transliteration in the Latin alphabet and back,
+    /// every Latin string of the table must have a single Cyrillic source.
+    Both,
+    /// The same as `Both`, besides the Latin strings must be uniquely decodable,
+    /// so that the Cyrillic text is always restored, see [`analyze`](crate::analyze).
+    Lossless,
+}
+
+/// The builder of a [`Transliterator`] based on a table or on a built-in scheme.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{Gost779B, Language, ToLatin, TransliteratorBuilder};
+/// let trasliterator = TransliteratorBuilder::from(Gost779B::new(Language::Ru))
+///     .insert("ы", "y")
+///     .insert(" ", "-")
+///     .insert("ь", "")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!("obshhie-voprosy", trasliterator.to_latin("общие вопросы"));
+/// assert_eq!("sol", trasliterator.to_latin("соль"));
+///
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransliteratorBuilder {
+    rules: Vec<Rule>,
+    case_aware: bool,
+    unmapped: UnmappedPolicy,
+    direction: Direction,
+}
+
+impl TransliteratorBuilder {
+    /// Creates a builder without rules.
+    pub fn new() -> Self {
+        Self::from_rules(Vec::new())
+    }
+
+    /// Creates a builder with a transliteration table.
+    pub fn from_table(table: CharsMapping) -> Self {
+        Self::from_rules(table.into_iter().map(Rule::from).collect())
+    }
+
+    /// Creates a builder with context-sensitive rules.
+    pub fn from_rules(rules: Vec<Rule>) -> Self {
+        TransliteratorBuilder {
+            rules,
+            case_aware: true,
+            unmapped: UnmappedPolicy::Keep,
+            direction: Direction::ToLatin,
+        }
+    }
+
+    /// Sets the mapping of `source`, all rules with this source string are replaced.
+    pub fn insert(self, source: &'static str, translit: &'static str) -> Self {
+        self.remove(source).rule(Rule::new(source, translit))
+    }
+
+    /// Adds a rule which takes precedence over the rules with the same source string.
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.insert(0, rule);
+        self
+    }
+
+    /// Removes all rules with the source string `source`.
+    pub fn remove(self, source: &str) -> Self {
+        self.retain(|rule| rule.source != source)
+    }
+
+    /// Keeps only the rules for which `keep` returns `true`.
+    pub fn retain<F: FnMut(&Rule) -> bool>(mut self, keep: F) -> Self {
+        self.rules.retain(keep);
+        self
+    }
+
+    /// Sets whether the case of the output follows the input (it does by default):
+    /// digraphs are recognized and produced in title case and in capitals.
+    pub fn case_aware(mut self, case_aware: bool) -> Self {
+        self.case_aware = case_aware;
+        self
+    }
+
+    /// Sets what to do with the letters which have no rule, they are kept by default.
+    pub fn unmapped(mut self, policy: UnmappedPolicy) -> Self {
+        self.unmapped = policy;
+        self
+    }
+
+    /// Sets the directions to validate the rules for, only `ToLatin` by default.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Validates the rules and creates the transliterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found:
+    /// an empty source string, a rule hidden by a previous rule with the same source
+    /// and no conditions, and for `Direction::Both` an empty Latin string or
+    /// a Latin string of several Cyrillic sources, for `Direction::Lossless`
+    /// a Latin string with two readings.
+    pub fn build(self) -> Result<Transliterator, BuildError> {
+        for (i, rule) in self.rules.iter().enumerate() {
+            let previous = &self.rules[..i];
+
+            if rule.source.is_empty() {
+                return Err(BuildError::EmptySource {
+                    translit: rule.translit,
+                });
+            }
+            if previous.iter().any(|prev| {
+                prev.source == rule.source && prev.before.is_any() && prev.after.is_any()
+            }) {
+                return Err(BuildError::UnreachableRule {
+                    source: rule.source,
+                    translit: rule.translit,
+                });
+            }
+
+            if self.direction != Direction::ToLatin {
+                if rule.translit.is_empty() {
+                    return Err(BuildError::EmptyTranslit {
+                        source: rule.source,
+                    });
+                }
+                if let Some(prev) = previous.iter().find(|prev| {
+                    prev.translit == rule.translit && !same_letters(prev.source, rule.source)
+                }) {
+                    return Err(BuildError::AmbiguousTranslit {
+                        translit: rule.translit,
+                        first: prev.source,
+                        second: rule.source,
+                    });
+                }
+            }
+        }
+
+        if self.direction == Direction::Lossless {
+            if let Some(ambiguity) = analyze_rules(&self.rules).ambiguity {
+                return Err(BuildError::NotUniquelyDecodable(ambiguity));
+            }
+        }
+
+        Ok(Transliterator::with_options(
+            self.rules,
+            self.case_aware,
+            self.unmapped,
+        ))
+    }
+}
+
+impl Default for TransliteratorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Transliterator> for TransliteratorBuilder {
+    fn from(translit: Transliterator) -> Self {
+        let case_aware = translit.is_case_aware();
+        let unmapped = translit.unmapped().clone();
+
+        TransliteratorBuilder {
+            rules: translit.rules().to_vec(),
+            case_aware,
+            unmapped,
+            direction: Direction::ToLatin,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use crate::ngram::{self, LetterModel};
+use crate::transliterator::Transliterator;
+
+/// A Cyrillic text which is transliterated to the given Latin text,
+/// see [`Transliterator::candidates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    /// The Cyrillic text.
+    pub text: String,
+    /// The natural logarithm of the probability of the text in the letter model,
+    /// higher is more likely.
+    pub score: f64,
+}
+
+/// The last two letters and whether a letter without a Latin string may follow.
+type State = ([char; 2], bool);
+
+/// How many partial texts are kept for every state per requested candidate,
+/// some of the texts are dropped by the final check.
+const SPARE: usize = 4;
+
+/// The most partial texts kept for every state, whatever the `limit`.
+const MAX_WIDTH: usize = 64;
+
+/// The vowels and `й`, after which a letter without a Latin string, like `ь`, is not tried.
+const VOWELS: &str = "аеёиоуыэюяєіїй";
+
+impl Transliterator {
+    /// Returns up to `limit` Cyrillic texts which are transliterated to `src`,
+    /// the most likely first according to `model`.
+    ///
+    /// This works for tables which cannot be reversed: a Latin string of several
+    /// letters gives a candidate for each of them, and letters transliterated
+    /// to an empty string (like `ь`) are tried after every consonant but `й`.
+    /// Every candidate is checked by the transliteration to the Latin alphabet.
+    ///
+    /// The search keeps up to `4 * limit`, but at most 64, partial texts for every
+    /// combination of the last two letters, so the time grows with the length of `src`
+    /// and with `limit` up to 16. A larger `limit` may return fewer candidates
+    /// than there are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{iternational_passport_2013_ru, LetterModel, Transliterator};
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// let candidates = trasliterator.candidates("Aleksei", LetterModel::russian(), 3);
+    /// assert_eq!("Алексей", candidates[0].text);
+    ///
+    /// ```
+    pub fn candidates(&self, src: &str, model: &LetterModel, limit: usize) -> Vec<Candidate> {
+        let mut letters: Vec<(String, String)> = Vec::new();
+        let mut silent: Vec<String> = Vec::new();
+        for rule in self.rules().iter().filter(|rule| !rule.source.is_empty()) {
+            let source = rule.source.to_lowercase();
+            if rule.translit.is_empty() {
+                if !silent.contains(&source) {
+                    silent.push(source);
+                }
+            } else {
+                let pair = (rule.translit.to_lowercase(), source);
+                if !letters.contains(&pair) {
+                    letters.push(pair);
+                }
+            }
+        }
+
+        let capacity = limit.saturating_mul(SPARE).min(MAX_WIDTH);
+        let mut states: Vec<HashMap<State, Vec<Candidate>>> = vec![HashMap::new(); src.len() + 1];
+        let mut finished = Vec::new();
+        states[0].insert((ngram::start(), false), vec![empty()]);
+
+        for pos in 0..=src.len() {
+            let mut current: Vec<(State, Vec<Candidate>)> = states[pos].drain().collect();
+            let before = src[..pos].chars().next_back();
+            let after = src[pos..].chars().next();
+
+            // a silent letter is in capitals between capitals, as "Ь" in "OLGA"
+            let upper =
+                before.is_some_and(char::is_uppercase) && !after.is_some_and(char::is_lowercase);
+            let mut inserted = HashMap::new();
+            for &((context, allowed), ref partials) in &current {
+                if !allowed || VOWELS.contains(context[1]) {
+                    continue;
+                }
+                for source in &silent {
+                    let text = if upper {
+                        source.to_uppercase()
+                    } else {
+                        source.clone()
+                    };
+                    for partial in partials {
+                        let (next, context) = extend(partial, context, &text, model);
+                        push(&mut inserted, (context, false), next, capacity);
+                    }
+                }
+            }
+            current.extend(inserted);
+
+            for ((context, _), partials) in current {
+                if pos == src.len() {
+                    for mut partial in partials {
+                        if context[1] != ngram::end() {
+                            partial.score += model.next_log_prob(context, ngram::end());
+                        }
+                        finished.push(partial);
+                    }
+                    continue;
+                }
+
+                let rest = &src[pos..];
+                let mut matched = false;
+                for (translit, source) in &letters {
+                    if let Some(len) = match_len(rest, translit) {
+                        matched = true;
+                        let text = apply_case(source, &rest[..len]);
+                        for partial in &partials {
+                            let (next, context) = extend(partial, context, &text, model);
+                            push(&mut states[pos + len], (context, true), next, capacity);
+                        }
+                    }
+                }
+
+                if !matched {
+                    let c = after.unwrap_or_default();
+                    for partial in &partials {
+                        let (next, context) =
+                            extend(partial, context, c.encode_utf8(&mut [0; 4]), model);
+                        push(
+                            &mut states[pos + c.len_utf8()],
+                            (context, false),
+                            next,
+                            capacity,
+                        );
+                    }
+                }
+            }
+        }
+
+        finished.sort_by(|a, b| b.score.total_cmp(&a.score));
+        let mut candidates: Vec<Candidate> = Vec::new();
+        for candidate in finished {
+            if candidates.len() == limit {
+                break;
+            }
+            if !candidates.iter().any(|other| other.text == candidate.text)
+                && self.convert(&candidate.text, false) == src
+            {
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+fn empty() -> Candidate {
+    Candidate {
+        text: String::new(),
+        score: 0.0,
+    }
+}
+
+/// Appends `text` to the partial candidate and scores its letters.
+fn extend(
+    partial: &Candidate,
+    mut context: [char; 2],
+    text: &str,
+    model: &LetterModel,
+) -> (Candidate, [char; 2]) {
+    let mut next = partial.clone();
+    next.text.push_str(text);
+
+    for c in text.chars().map(ngram::normalize) {
+        if c == ngram::end() && context[1] == ngram::end() {
+            continue;
+        }
+        next.score += model.next_log_prob(context, c);
+        context = [context[1], c];
+    }
+
+    (next, context)
+}
+
+/// Keeps the `capacity` best partial candidates of the state.
+fn push(
+    states: &mut HashMap<State, Vec<Candidate>>,
+    state: State,
+    partial: Candidate,
+    capacity: usize,
+) {
+    let partials = states.entry(state).or_default();
+    let pos = partials
+        .iter()
+        .position(|other| other.score < partial.score)
+        .unwrap_or(partials.len());
+    if pos < capacity {
+        partials.insert(pos, partial);
+        partials.truncate(capacity);
+    }
+}
+
+/// Returns the byte length of the prefix of `text` equal to `key` ignoring case.
+fn match_len(text: &str, key: &str) -> Option<usize> {
+    let mut len = 0;
+    let mut chars = text.chars();
+
+    for k in key.chars() {
+        let c = chars.next()?;
+        if c.to_lowercase().ne(Some(k)) {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+
+    Some(len)
+}
+
+/// Gives the lowercase `source` the case of the Latin text it was read from:
+/// "Zgh" -> "Зг", "ZGH" -> "ЗГ".
+fn apply_case(source: &str, latin: &str) -> String {
+    let mut latin_chars = latin.chars();
+    let first = latin_chars.next().is_some_and(char::is_uppercase);
+    let rest = latin_chars.any(char::is_uppercase);
+
+    let mut output = String::with_capacity(source.len());
+    for (i, c) in source.chars().enumerate() {
+        if (i == 0 && first) || (i > 0 && rest) {
+            output.extend(c.to_uppercase());
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+/// How the output of a rule has to be adjusted to the case of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    /// The key was matched exactly as written in the table.
+    Keep,
+    /// The key was matched with its first letter capitalized.
+    Title,
+    /// The key was matched in capital letters.
+    Upper,
+}
+
+impl Case {
+    /// Resolves the case of an exactly matched `key` from its neighbours.
+    ///
+    /// A capital key (or a key without letters, like "``") inside a run of capitals
+    /// produces capitals, so that "Ч" in "ВИЧ" gives "CH" and not "Ch".
+    pub(crate) fn resolve(self, key: &str, before: &str, after: &str) -> Case {
+        if self != Case::Keep || key.chars().any(char::is_lowercase) {
+            return self;
+        }
+
+        if upper_context(before, after) {
+            Case::Upper
+        } else {
+            Case::Keep
+        }
+    }
+
+    pub(crate) fn write<W: fmt::Write + ?Sized>(
+        self,
+        output: &mut W,
+        translit: &str,
+    ) -> fmt::Result {
+        match self {
+            Case::Keep => output.write_str(translit),
+            Case::Upper => translit
+                .chars()
+                .flat_map(char::to_uppercase)
+                .try_for_each(|c| output.write_char(c)),
+            Case::Title => {
+                let mut chars = translit.chars();
+                if let Some(first) = chars.next() {
+                    first
+                        .to_uppercase()
+                        .try_for_each(|c| output.write_char(c))?;
+                }
+                output.write_str(chars.as_str())
+            }
+        }
+    }
+}
+
+/// Checks that the strings differ only in case, like "Ь" and "ь".
+///
+/// Such sources may share a Latin string without "``" like output:
+/// the case is restored from the neighbours.
+pub(crate) fn same_letters(a: &str, b: &str) -> bool {
+    a == b || a.to_lowercase() == b.to_lowercase()
+}
+
+/// Returns the key with the first letter capitalized.
+pub(crate) fn to_title(key: &str) -> String {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn is_cased(c: &char) -> bool {
+    c.is_lowercase() || c.is_uppercase()
+}
+
+/// Marks which are a part of a Latin letter, like "`" in "U`"
+/// or the combining macron in "Z̄".
+pub(crate) fn is_mark(c: &char) -> bool {
+    *c == '`' || ('\u{300}'..='\u{36f}').contains(c)
+}
+
+/// The word is written in capitals if the nearest letter is a capital one.
+/// The next letter is checked first, so the first letter of "Щукин" stays in title case.
+/// Marks are skipped, so "``" in "U``" follows the capital "U`".
+fn upper_context(before: &str, after: &str) -> bool {
+    let next = after.chars().find(|c| !is_mark(c));
+    match next.filter(is_cased) {
+        Some(c) => c.is_uppercase(),
+        None => before
+            .chars()
+            .rev()
+            .find(|c| !is_mark(c))
+            .is_some_and(char::is_uppercase),
+    }
+}
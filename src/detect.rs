@@ -0,0 +1,525 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::transliterator::{
+    AlaLc, BgnPcgn, BulgarianOfficial, Gost779A, Gost779B, Kazakh2021, Language,
+    MacedonianOfficial, MongolianMns5217, OrderN995, Passport2013, Scholarly, SerbianOfficial,
+    Transliterator, UkrainianNational2010, UzbekOfficial,
+};
+
+/// The built-in transliteration standards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Standard {
+    /// [`Gost779A`].
+    Gost779A,
+    /// [`Gost779B`] for the language.
+    Gost779B(Language),
+    /// [`Passport2013`].
+    Passport2013,
+    /// [`OrderN995`].
+    OrderN995,
+    /// [`UkrainianNational2010`].
+    UkrainianNational2010,
+    /// [`BulgarianOfficial`].
+    BulgarianOfficial,
+    /// [`MacedonianOfficial`].
+    MacedonianOfficial,
+    /// [`SerbianOfficial`].
+    SerbianOfficial,
+    /// [`Kazakh2021`].
+    Kazakh2021,
+    /// [`UzbekOfficial`].
+    UzbekOfficial,
+    /// [`MongolianMns5217`], with the diacritics.
+    MongolianMns5217,
+    /// [`BgnPcgn`] for the language, without the separator.
+    BgnPcgn(Language),
+    /// [`AlaLc`] for the language, with the diacritics.
+    AlaLc(Language),
+    /// [`Scholarly`] for the language.
+    Scholarly(Language),
+}
+
+impl Standard {
+    /// All built-in standards.
+    pub const ALL: &'static [Standard] = &[
+        Standard::Gost779A,
+        Standard::Gost779B(Language::Ru),
+        Standard::Gost779B(Language::By),
+        Standard::Gost779B(Language::Ua),
+        Standard::Gost779B(Language::Bg),
+        Standard::Gost779B(Language::Mk),
+        Standard::Gost779B(Language::Sr),
+        Standard::Gost779B(Language::Kz),
+        Standard::Gost779B(Language::Kg),
+        Standard::Gost779B(Language::Tj),
+        Standard::Gost779B(Language::Uz),
+        Standard::Gost779B(Language::Tm),
+        Standard::Gost779B(Language::Az),
+        Standard::Gost779B(Language::Mn),
+        Standard::Gost779B(Language::Bua),
+        Standard::Gost779B(Language::Md),
+        Standard::Gost779B(Language::Ab),
+        Standard::Gost779B(Language::Ba),
+        Standard::Gost779B(Language::Tt),
+        Standard::Gost779B(Language::Cv),
+        Standard::Gost779B(Language::Sah),
+        Standard::Gost779B(Language::Alt),
+        Standard::Gost779B(Language::Chm),
+        Standard::Gost779B(Language::Udm),
+        Standard::Passport2013,
+        Standard::OrderN995,
+        Standard::UkrainianNational2010,
+        Standard::BulgarianOfficial,
+        Standard::MacedonianOfficial,
+        Standard::SerbianOfficial,
+        Standard::Kazakh2021,
+        Standard::UzbekOfficial,
+        Standard::MongolianMns5217,
+        Standard::BgnPcgn(Language::Ru),
+        Standard::BgnPcgn(Language::Ua),
+        Standard::BgnPcgn(Language::By),
+        Standard::BgnPcgn(Language::Bg),
+        Standard::AlaLc(Language::Ru),
+        Standard::AlaLc(Language::Ua),
+        Standard::AlaLc(Language::By),
+        Standard::Scholarly(Language::Ru),
+        Standard::Scholarly(Language::Ua),
+        Standard::Scholarly(Language::By),
+        Standard::Scholarly(Language::Bg),
+        Standard::Scholarly(Language::Mk),
+        Standard::Scholarly(Language::Sr),
+    ];
+
+    /// The national romanization of the country of the language, `None` if there is
+    /// no such standard in this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{Language, Standard};
+    /// assert_eq!(Some(Standard::MongolianMns5217), Standard::national(Language::Mn));
+    ///
+    /// let trasliterator = Standard::national(Language::Mn)
+    ///     .and_then(Standard::transliterator)
+    ///     .unwrap();
+    /// assert_eq!("Ölgii", trasliterator.convert("Өлгий", false));
+    ///
+    /// ```
+    pub fn national(lang: Language) -> Option<Standard> {
+        match lang {
+            Language::Ru => Some(Standard::Passport2013),
+            Language::Ua => Some(Standard::UkrainianNational2010),
+            Language::Bg => Some(Standard::BulgarianOfficial),
+            Language::Mk => Some(Standard::MacedonianOfficial),
+            Language::Sr => Some(Standard::SerbianOfficial),
+            Language::Kz => Some(Standard::Kazakh2021),
+            Language::Uz => Some(Standard::UzbekOfficial),
+            Language::Mn => Some(Standard::MongolianMns5217),
+            _ => None,
+        }
+    }
+
+    /// Creates the transliterator of the standard, `None` for [`Standard::BgnPcgn`],
+    /// [`Standard::AlaLc`] and [`Standard::Scholarly`] of a language without a system.
+    ///
+    /// The transliterator holds the table only: from the Latin alphabet it does not
+    /// split the Serbian digraphs of the exception list or keep foreign words,
+    /// and does not read the Uzbek apostrophes, use [`SerbianOfficial`] and
+    /// [`UzbekOfficial`] for that.
+    pub fn transliterator(self) -> Option<Transliterator> {
+        let translit = match self {
+            Standard::Gost779A => Gost779A::new().as_ref().clone(),
+            Standard::Gost779B(lang) => Gost779B::new(lang).as_ref().clone(),
+            Standard::Passport2013 => Passport2013::new().as_ref().clone(),
+            Standard::OrderN995 => OrderN995::new().as_ref().clone(),
+            Standard::UkrainianNational2010 => UkrainianNational2010::new().as_ref().clone(),
+            Standard::BulgarianOfficial => BulgarianOfficial::new().as_ref().clone(),
+            Standard::MacedonianOfficial => MacedonianOfficial::new().as_ref().clone(),
+            Standard::SerbianOfficial => SerbianOfficial::new().as_ref().clone(),
+            Standard::Kazakh2021 => Kazakh2021::new().as_ref().clone(),
+            Standard::UzbekOfficial => UzbekOfficial::new().as_ref().clone(),
+            Standard::MongolianMns5217 => MongolianMns5217::new().as_ref().clone(),
+            Standard::BgnPcgn(lang) => BgnPcgn::new(lang)?.as_ref().clone(),
+            Standard::AlaLc(lang) => AlaLc::new(lang)?.as_ref().clone(),
+            Standard::Scholarly(lang) => Scholarly::new(lang)?.as_ref().clone(),
+        };
+        Some(translit)
+    }
+}
+
+impl fmt::Display for Standard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Standard::Gost779A => f.write_str("GOST 7.79 System A"),
+            Standard::Gost779B(lang) => write!(f, "GOST 7.79 System B ({:?})", lang),
+            Standard::Passport2013 => f.write_str("Passport 2013"),
+            Standard::OrderN995 => f.write_str("Order N 995"),
+            Standard::UkrainianNational2010 => f.write_str("Ukrainian National 2010"),
+            Standard::BulgarianOfficial => f.write_str("Bulgarian Official"),
+            Standard::MacedonianOfficial => f.write_str("Macedonian Official"),
+            Standard::SerbianOfficial => f.write_str("Serbian Official"),
+            Standard::Kazakh2021 => f.write_str("Kazakh 2021"),
+            Standard::UzbekOfficial => f.write_str("Uzbek Official"),
+            Standard::MongolianMns5217 => f.write_str("MNS 5217:2012"),
+            Standard::BgnPcgn(lang) => write!(f, "BGN/PCGN ({:?})", lang),
+            Standard::AlaLc(lang) => write!(f, "ALA-LC ({:?})", lang),
+            Standard::Scholarly(lang) => write!(f, "Scholarly ({:?})", lang),
+        }
+    }
+}
+
+/// How well a standard fits a Latin text, see [`detect_standard`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    /// The standard.
+    pub standard: Standard,
+    /// The share of the characters which agree with the standard, from 0 to 1.
+    pub fit: f64,
+    /// The characters which disagree with the standard.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Detection {
+    /// Checks that the standard could have produced the whole text.
+    pub fn is_exact(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// A part of the Latin text which disagrees with a standard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The byte offset in the Latin text.
+    pub offset: usize,
+    /// The characters of the Latin text, empty if the standard produces more characters.
+    pub found: String,
+    /// The characters the standard produces instead,
+    /// `None` if the Cyrillic original is unknown.
+    pub expected: Option<String>,
+}
+
+/// Reports how well every built-in standard fits the Latin text, the best fitting first.
+///
+/// With the Cyrillic `original` the text is compared with its transliteration by each standard.
+/// Without it a standard fits if the text can be composed of its Latin strings,
+/// the contexts of the rules are not checked. Letters and marks like "`" which
+/// the standard never produces disagree, other characters are ignored.
+///
+/// Standards which fit equally well are ranked by how typical the text is for them.
+/// A standard which produces letters or marks outside ASCII ranks lower if the text
+/// has none of them: "Moskva" is rather [`Standard::Passport2013`] than
+/// [`Standard::Gost779A`]. Without the original the text is read with as few
+/// Cyrillic letters as possible, so "Shchukin" is rather a standard with `щ` as "shch"
+/// than one with `ш` as "sh" and `ч` as "ch".
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{detect_standard, Standard};
+/// let detections = detect_standard("Shchukin", Some("Щукин"));
+/// assert_eq!(Standard::Passport2013, detections[0].standard);
+/// assert!(detections[0].is_exact());
+///
+/// let detections = detect_standard("Xrushhyov", None);
+/// assert!(detections[0].is_exact());
+/// assert!(matches!(detections[0].standard, Standard::Gost779B(_)));
+///
+/// ```
+pub fn detect_standard(latin: &str, original: Option<&str>) -> Vec<Detection> {
+    let (standards, marks) = prepared();
+    let letters: HashSet<char> = latin.chars().flat_map(char::to_lowercase).collect();
+
+    let mut detections: Vec<(Detection, bool, usize)> = standards
+        .iter()
+        .map(|prepared| {
+            let (fit, pieces, mismatches) = match original {
+                Some(original) => {
+                    let (fit, mismatches) =
+                        compare(latin, &prepared.translit.convert(original, false));
+                    (fit, 0, mismatches)
+                }
+                None => cover(latin, &prepared.keys, marks),
+            };
+            let untypical =
+                !prepared.distinctive.is_empty() && prepared.distinctive.is_disjoint(&letters);
+            let detection = Detection {
+                standard: prepared.standard,
+                fit,
+                mismatches,
+            };
+            (detection, untypical, pieces)
+        })
+        .collect();
+
+    detections.sort_by(|(a, a_untypical, a_pieces), (b, b_untypical, b_pieces)| {
+        b.fit
+            .total_cmp(&a.fit)
+            .then(a_untypical.cmp(b_untypical))
+            .then(a_pieces.cmp(b_pieces))
+    });
+    detections
+        .into_iter()
+        .map(|(detection, _, _)| detection)
+        .collect()
+}
+
+/// A built-in standard prepared for [`detect_standard`].
+struct Prepared {
+    standard: Standard,
+    translit: Transliterator,
+    /// The lowercase Latin strings of the rules.
+    keys: Vec<Vec<char>>,
+    /// The lowercase characters outside ASCII which the standard produces.
+    distinctive: HashSet<char>,
+}
+
+/// The built-in standards and the marks produced by any of them, built once.
+fn prepared() -> &'static (Vec<Prepared>, HashSet<char>) {
+    static PREPARED: OnceLock<(Vec<Prepared>, HashSet<char>)> = OnceLock::new();
+    PREPARED.get_or_init(|| {
+        let standards: Vec<Prepared> = Standard::ALL
+            .iter()
+            .filter_map(|&standard| {
+                let translit = standard.transliterator()?;
+                let outputs: HashSet<String> = translit
+                    .rules()
+                    .iter()
+                    .filter(|rule| !rule.translit.is_empty())
+                    .map(|rule| rule.translit.to_lowercase())
+                    .collect();
+                let distinctive = outputs
+                    .iter()
+                    .flat_map(|output| output.chars())
+                    .filter(|c| !c.is_ascii())
+                    .collect();
+                Some(Prepared {
+                    standard,
+                    translit,
+                    keys: outputs
+                        .iter()
+                        .map(|output| output.chars().collect())
+                        .collect(),
+                    distinctive,
+                })
+            })
+            .collect();
+
+        let marks = standards
+            .iter()
+            .flat_map(|prepared| prepared.translit.rules())
+            .flat_map(|rule| rule.translit.chars())
+            .filter(|c| !c.is_alphabetic())
+            .collect();
+
+        (standards, marks)
+    })
+}
+
+/// Aligns the text with the expected one by the edit distance,
+/// word by word if the texts have as many spaces.
+fn compare(latin: &str, expected: &str) -> (f64, Vec<Mismatch>) {
+    let found: Vec<char> = latin.chars().collect();
+    let wanted: Vec<char> = expected.chars().collect();
+    let found_spaces = spaces(&found);
+    let wanted_spaces = spaces(&wanted);
+
+    let mut steps = Vec::new();
+    if found_spaces.len() == wanted_spaces.len() {
+        let (mut i, mut j) = (0, 0);
+        for (&space_i, &space_j) in found_spaces.iter().zip(&wanted_spaces) {
+            align(&found[i..space_i], i, &wanted[j..space_j], j, &mut steps);
+            steps.push((Some(space_i), Some(space_j)));
+            (i, j) = (space_i + 1, space_j + 1);
+        }
+        align(&found[i..], i, &wanted[j..], j, &mut steps);
+    } else {
+        align(&found, 0, &wanted, 0, &mut steps);
+    }
+
+    // consecutive edit steps are one mismatch
+    let mut mismatches = Vec::new();
+    let mut run: Option<(usize, String, String)> = None;
+    let mut offset = 0;
+    let mut distance = 0;
+    for (i, j) in steps {
+        let found_char = i.map(|i| found[i]);
+        let wanted_char = j.map(|j| wanted[j]);
+
+        if found_char.is_some() && found_char == wanted_char {
+            mismatches.extend(run.take().map(mismatch));
+        } else {
+            let (_, found, expected) =
+                run.get_or_insert_with(|| (offset, String::new(), String::new()));
+            found.extend(found_char);
+            expected.extend(wanted_char);
+            distance += 1;
+        }
+
+        offset += found_char.map_or(0, char::len_utf8);
+    }
+    mismatches.extend(run.map(mismatch));
+
+    let length = found.len().max(wanted.len());
+    let fit = if length == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / length as f64
+    };
+    (fit, mismatches)
+}
+
+/// Appends the steps of the alignment with the fewest edits, the pairs of the indices
+/// of the characters shifted by `i` and `j`, `None` for an inserted or a removed character.
+///
+/// The text is split in halves and the expected text where the distances of the halves
+/// add up to the least (Hirschberg's algorithm), so only two rows of distances are kept.
+fn align(
+    found: &[char],
+    i: usize,
+    wanted: &[char],
+    j: usize,
+    steps: &mut Vec<(Option<usize>, Option<usize>)>,
+) {
+    match found {
+        [] => steps.extend((j..j + wanted.len()).map(|j| (None, Some(j)))),
+        [c] => {
+            // the character matches its first occurrence or replaces the first character
+            let Some(kept) = wanted
+                .iter()
+                .position(|w| w == c)
+                .or(wanted.first().map(|_| 0))
+            else {
+                steps.push((Some(i), None));
+                return;
+            };
+            steps.extend((j..j + kept).map(|j| (None, Some(j))));
+            steps.push((Some(i), Some(j + kept)));
+            steps.extend((j + kept + 1..j + wanted.len()).map(|j| (None, Some(j))));
+        }
+        _ => {
+            let middle = found.len() / 2;
+            let head = distances(found[..middle].iter(), wanted.iter());
+            let tail = distances(found[middle..].iter().rev(), wanted.iter().rev());
+            let split = (0..=wanted.len())
+                .min_by_key(|&split| head[split] + tail[wanted.len() - split])
+                .unwrap_or(0);
+
+            align(&found[..middle], i, &wanted[..split], j, steps);
+            align(
+                &found[middle..],
+                i + middle,
+                &wanted[split..],
+                j + split,
+                steps,
+            );
+        }
+    }
+}
+
+/// The edit distances of the text from every prefix of the expected text.
+fn distances<'a>(
+    found: impl Iterator<Item = &'a char>,
+    wanted: impl Iterator<Item = &'a char> + Clone,
+) -> Vec<usize> {
+    let mut row: Vec<usize> = (0..=wanted.clone().count()).collect();
+    for (i, f) in found.enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, w) in wanted.clone().enumerate() {
+            let replace = diagonal + usize::from(f != w);
+            diagonal = row[j + 1];
+            row[j + 1] = replace.min(row[j + 1] + 1).min(row[j] + 1);
+        }
+    }
+    row
+}
+
+/// The indices of the whitespace characters.
+fn spaces(text: &[char]) -> Vec<usize> {
+    (0..text.len())
+        .filter(|&i| text[i].is_whitespace())
+        .collect()
+}
+
+fn mismatch((offset, found, expected): (usize, String, String)) -> Mismatch {
+    Mismatch {
+        offset,
+        found,
+        expected: Some(expected),
+    }
+}
+
+/// The numbers of the uncovered characters and of the strings covering the text.
+type Cost = (usize, usize);
+
+/// Covers the text with the Latin strings of a standard, leaving as few letters
+/// and marks as possible and then using as few strings as possible.
+///
+/// Returns the fit, the number of the strings and the uncovered characters.
+fn cover(latin: &str, keys: &[Vec<char>], marks: &HashSet<char>) -> (f64, usize, Vec<Mismatch>) {
+    let chars: Vec<(usize, char)> = latin.char_indices().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|&(_, c)| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+    let counted = |c: char| c.is_alphabetic() || marks.contains(&c);
+
+    // the least cost of the prefix and the step which reaches it
+    let mut best: Vec<Option<(Cost, usize, bool)>> = vec![None; chars.len() + 1];
+    best[0] = Some(((0, 0), 0, true));
+    for i in 0..chars.len() {
+        let Some(((uncovered, pieces), _, _)) = best[i] else {
+            continue;
+        };
+        let mut relax = |end: usize, cost: Cost, covered: bool| {
+            if best[end].is_none_or(|(other, _, _)| cost < other) {
+                best[end] = Some((cost, i, covered));
+            }
+        };
+
+        for key in keys {
+            if lower[i..].starts_with(key) {
+                relax(i + key.len(), (uncovered, pieces + 1), true);
+            }
+        }
+        let skipped = counted(chars[i].1);
+        relax(i + 1, (uncovered + usize::from(skipped), pieces), !skipped);
+    }
+
+    let mut mismatches: Vec<Mismatch> = Vec::new();
+    let mut end = chars.len();
+    while end > 0 {
+        let Some((_, start, covered)) = best[end] else {
+            break;
+        };
+        if !covered {
+            let (offset, c) = chars[start];
+            match mismatches.last_mut() {
+                Some(last) if last.offset == offset + c.len_utf8() => {
+                    last.offset = offset;
+                    last.found.insert(0, c);
+                }
+                _ => mismatches.push(Mismatch {
+                    offset,
+                    found: c.to_string(),
+                    expected: None,
+                }),
+            }
+        }
+        end = start;
+    }
+    mismatches.reverse();
+
+    let total = chars.iter().filter(|&&(_, c)| counted(c)).count();
+    let (uncovered, pieces) = best[chars.len()].map_or((0, 0), |(cost, _, _)| cost);
+    let fit = if total == 0 {
+        1.0
+    } else {
+        1.0 - uncovered as f64 / total as f64
+    };
+    (fit, pieces, mismatches)
+}
@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::analysis::Ambiguity;
+
+/// The error of the strict transliteration:
+/// the input contains letters of the source script which have no rule in the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslitError {
+    unmapped: Vec<(usize, char)>,
+}
+
+impl TranslitError {
+    pub(crate) fn new(unmapped: Vec<(usize, char)>) -> Self {
+        TranslitError { unmapped }
+    }
+
+    /// The characters without a rule with their byte offsets in the input.
+    pub fn unmapped(&self) -> &[(usize, char)] {
+        &self.unmapped
+    }
+}
+
+impl fmt::Display for TranslitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("untransliterated characters:")?;
+        for (i, (offset, c)) in self.unmapped.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{}{:?} at byte {}", separator, c, offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for TranslitError {}
+
+/// The error of [`Transliterator::verify_round_trip`](crate::Transliterator::verify_round_trip):
+/// the transliteration back from the Latin alphabet differs from the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTripError {
+    offset: usize,
+    translit: String,
+    restored: String,
+}
+
+impl RoundTripError {
+    pub(crate) fn new(offset: usize, translit: String, restored: String) -> Self {
+        RoundTripError {
+            offset,
+            translit,
+            restored,
+        }
+    }
+
+    /// The byte offset of the first difference, in both the input and the restored text.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The transliteration of the input.
+    pub fn translit(&self) -> &str {
+        &self.translit
+    }
+
+    /// The text restored from the transliteration.
+    pub fn restored(&self) -> &str {
+        &self.restored
+    }
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is restored as {:?}, the first difference at byte {}",
+            self.translit, self.restored, self.offset
+        )
+    }
+}
+
+impl Error for RoundTripError {}
+
+/// The error of [`TransliteratorBuilder::build`](crate::TransliteratorBuilder::build).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// The source string of the rule is empty.
+    EmptySource { translit: &'static str },
+    /// The rule is never applied, a previous rule has the same source string and no conditions.
+    UnreachableRule {
+        source: &'static str,
+        translit: &'static str,
+    },
+    /// The Latin string of the rule is empty, so it cannot be transliterated back.
+    EmptyTranslit { source: &'static str },
+    /// The rules with different source strings have the same Latin string.
+    AmbiguousTranslit {
+        translit: &'static str,
+        first: &'static str,
+        second: &'static str,
+    },
+    /// The Latin string has two different readings.
+    NotUniquelyDecodable(Ambiguity),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::EmptySource { translit } => {
+                write!(f, "empty source string for {:?}", translit)
+            }
+            BuildError::UnreachableRule { source, translit } => write!(
+                f,
+                "rule {:?} -> {:?} is hidden by a previous rule",
+                source, translit
+            ),
+            BuildError::EmptyTranslit { source } => write!(
+                f,
+                "{:?} is transliterated to an empty string and cannot be restored",
+                source
+            ),
+            BuildError::AmbiguousTranslit {
+                translit,
+                first,
+                second,
+            } => write!(
+                f,
+                "{:?} is the transliteration of both {:?} and {:?}",
+                translit, first, second
+            ),
+            BuildError::NotUniquelyDecodable(ambiguity) => write!(
+                f,
+                "{:?} can be read as both {:?} and {:?}",
+                ambiguity.translit,
+                ambiguity.first.concat(),
+                ambiguity.second.concat()
+            ),
+        }
+    }
+}
+
+impl Error for BuildError {}
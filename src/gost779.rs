@@ -1,9 +1,70 @@
 use super::CharsMapping;
+use crate::{Condition, Language, Rule};
+
+/// The letters before which `ц` is transliterated as `c` in the tables built by [`gost779b`],
+/// the letters which are missing in a language do no harm.
+const FRONT: &str = "еёиыэюяйієїјӗӣӥӹ";
+
+/// GOST 7.79 System B transliterates `ц` as `c` before the letters
+/// `front` (they are transliterated starting with i, e, y or j) and as `cz` in other cases.
+fn letter_tse(front: &'static str, table: CharsMapping) -> Vec<Rule> {
+    let mut rules = vec![
+        Rule::new("Ц", "C").after(Condition::OneOf(front)),
+        Rule::new("Ц", "Cz"),
+        Rule::new("ц", "c").after(Condition::OneOf(front)),
+        Rule::new("ц", "cz"),
+    ];
+    rules.extend(
+        table
+            .into_iter()
+            .filter(|&(source, _)| source != "Ц" && source != "ц")
+            .map(Rule::from),
+    );
+    rules
+}
+
+/// Cyrillic Russian transliteration rules.
+/// implementation GOST 7.79 System B, modified ISO 9:1995,
+/// including the context rules for the letter `ц`.
+///
+/// The standard has no rules for the end of a word: `ы` is "y`", `и` is "i"
+/// and `ый`, `ий` are "y`j", "ij" in any position.
+pub fn gost779b_ru_rules() -> Vec<Rule> {
+    letter_tse("еёиыэюяй", gost779b_ru())
+}
+
+/// Cyrillic Belarusian transliteration rules.
+/// implementation GOST 7.79 System B, modified ISO 9:1995,
+/// including the context rules for the letter `ц`.
+pub fn gost779b_by_rules() -> Vec<Rule> {
+    letter_tse("еёіыэюяй", gost779b_by())
+}
+
+/// Cyrillic Ukrainian transliteration rules.
+/// implementation GOST 7.79 System B, modified ISO 9:1995,
+/// including the context rules for the letter `ц`.
+pub fn gost779b_ua_rules() -> Vec<Rule> {
+    letter_tse("еиюяйєії", gost779b_ua())
+}
+
+/// Cyrillic transliteration rules for the language.
+/// implementation GOST 7.79 System B, modified ISO 9:1995,
+/// including the context rules for the letter `ц`.
+pub fn gost779b_rules(lang: Language) -> Vec<Rule> {
+    match lang {
+        Language::Ru => gost779b_ru_rules(),
+        Language::By => gost779b_by_rules(),
+        Language::Ua => gost779b_ua_rules(),
+        _ => letter_tse(FRONT, gost779b(lang)),
+    }
+}
 
 /// Cyrillic Russian transliteration table.
 /// implementation GOST 7.79 System B, modified ISO 9:1995.
 /// more details:
 /// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9)
+///
+/// The table has no context, the letter `ц` is always transliterated as `c`.
 pub fn gost779b_ru() -> CharsMapping {
     [
         ("А", "A"),
@@ -34,8 +95,10 @@ pub fn gost779b_ru() -> CharsMapping {
         ("Ш", "Sh"),
         ("Щ", "Shh"),
         ("ъ", "``"),
+        ("Ъ", "``"),
         ("Ы", "Y`"),
         ("ь", "`"),
+        ("Ь", "`"),
         ("Э", "E`"),
         ("Ю", "Yu"),
         ("Я", "Ya"),
@@ -81,12 +144,14 @@ pub fn gost779b_ru() -> CharsMapping {
 /// implementation GOST 7.79 System B, modified ISO 9:1995.
 /// more details:
 /// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9)
+///
+/// The table has no context, the letter `ц` is always transliterated as `c`.
 pub fn gost779b_by() -> CharsMapping {
     [
         ("А", "A"),
         ("Б", "B"),
         ("В", "V"),
-        ("Г", "H"),
+        ("Г", "G"),
         ("Д", "D"),
         ("Е", "E"),
         ("Ё", "Yo"),
@@ -111,6 +176,7 @@ pub fn gost779b_by() -> CharsMapping {
         ("Ш", "Sh"),
         ("Ы", "Y`"),
         ("ь", "`"),
+        ("Ь", "`"),
         ("Э", "E`"),
         ("Ю", "Yu"),
         ("Я", "Ya"),
@@ -118,7 +184,7 @@ pub fn gost779b_by() -> CharsMapping {
         ("а", "a"),
         ("б", "b"),
         ("в", "v"),
-        ("г", "h"),
+        ("г", "g"),
         ("д", "d"),
         ("е", "e"),
         ("ё", "yo"),
@@ -157,6 +223,8 @@ pub fn gost779b_by() -> CharsMapping {
 /// implementation GOST 7.79 System B, modified ISO 9:1995.
 /// more details:
 /// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9)
+///
+/// The table has no context, the letter `ц` is always transliterated as `c`.
 pub fn gost779b_ua() -> CharsMapping {
     [
         ("А", "A"),
@@ -186,6 +254,7 @@ pub fn gost779b_ua() -> CharsMapping {
         ("Ш", "Sh"),
         ("Щ", "Shh"),
         ("ь", "`"),
+        ("Ь", "`"),
         ("Ю", "Yu"),
         ("Я", "Ya"),
         ("Ґ", "G`"),
@@ -230,3 +299,542 @@ pub fn gost779b_ua() -> CharsMapping {
     .cloned()
     .collect()
 }
+
+/// Cyrillic transliteration table for the language.
+/// implementation GOST 7.79 System B, modified ISO 9:1995.
+/// more details:
+/// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9)
+///
+/// The table has no context, the letter `ц` is always transliterated as `c`.
+/// The letters of the non-Slavic languages are transliterated as the nearest Latin
+/// letter with "`", so a letter like `ң` -> "n`" can be confused with `нь`,
+/// which is rare in these languages.
+pub fn gost779b(lang: Language) -> CharsMapping {
+    match lang {
+        Language::Ru => gost779b_ru(),
+        Language::By => gost779b_by(),
+        Language::Ua => gost779b_ua(),
+        Language::Bg => alphabet(
+            "ёыэ",
+            &[
+                ("Щ", "Sht"),
+                ("Ъ", "A`"),
+                ("Ѣ", "Ye"),
+                ("Ѫ", "O`"),
+                ("щ", "sht"),
+                ("ъ", "a`"),
+                ("ѣ", "ye"),
+                ("ѫ", "o`"),
+            ],
+        ),
+        Language::Mk => alphabet(
+            "ёйщъыьэюя",
+            &[
+                ("Ѓ", "G`"),
+                ("Ѕ", "Z`"),
+                ("Ј", "J"),
+                ("Љ", "L`"),
+                ("Њ", "N`"),
+                ("Ќ", "K`"),
+                ("Џ", "Dh"),
+                ("ѓ", "g`"),
+                ("ѕ", "z`"),
+                ("ј", "j"),
+                ("љ", "l`"),
+                ("њ", "n`"),
+                ("ќ", "k`"),
+                ("џ", "dh"),
+            ],
+        ),
+        Language::Sr => alphabet(
+            "ёйщъыьэюя",
+            &[
+                ("Ђ", "D`"),
+                ("Ј", "J"),
+                ("Љ", "L`"),
+                ("Њ", "N`"),
+                ("Ћ", "C`"),
+                ("Џ", "Dh"),
+                ("ђ", "d`"),
+                ("ј", "j"),
+                ("љ", "l`"),
+                ("њ", "n`"),
+                ("ћ", "c`"),
+                ("џ", "dh"),
+            ],
+        ),
+        Language::Kz => alphabet(
+            "",
+            &[
+                ("Ә", "A`"),
+                ("Ғ", "G`"),
+                ("Қ", "K`"),
+                ("Ң", "N`"),
+                ("Ө", "O`"),
+                ("Ұ", "U``"),
+                ("Ү", "U`"),
+                ("Һ", "H"),
+                ("І", "I`"),
+                ("ә", "a`"),
+                ("ғ", "g`"),
+                ("қ", "k`"),
+                ("ң", "n`"),
+                ("ө", "o`"),
+                ("ұ", "u``"),
+                ("ү", "u`"),
+                ("һ", "h"),
+                ("і", "i`"),
+            ],
+        ),
+        Language::Kg => alphabet(
+            "",
+            &[
+                ("Ң", "N`"),
+                ("Ө", "O`"),
+                ("Ү", "U`"),
+                ("ң", "n`"),
+                ("ө", "o`"),
+                ("ү", "u`"),
+            ],
+        ),
+        Language::Tj => alphabet(
+            "",
+            &[
+                ("Ғ", "G`"),
+                ("Ӣ", "I`"),
+                ("Қ", "K`"),
+                ("Ӯ", "U`"),
+                ("Ҳ", "X`"),
+                ("Ҷ", "Ch`"),
+                ("ғ", "g`"),
+                ("ӣ", "i`"),
+                ("қ", "k`"),
+                ("ӯ", "u`"),
+                ("ҳ", "x`"),
+                ("ҷ", "ch`"),
+            ],
+        ),
+        Language::Uz => alphabet(
+            "",
+            &[
+                ("Ғ", "G`"),
+                ("Қ", "K`"),
+                ("Ў", "U`"),
+                ("Ҳ", "X`"),
+                ("ғ", "g`"),
+                ("қ", "k`"),
+                ("ў", "u`"),
+                ("ҳ", "x`"),
+            ],
+        ),
+        Language::Tm => alphabet(
+            "",
+            &[
+                ("Ә", "A`"),
+                ("Җ", "Zh`"),
+                ("Ң", "N`"),
+                ("Ө", "O`"),
+                ("Ү", "U`"),
+                ("ә", "a`"),
+                ("җ", "zh`"),
+                ("ң", "n`"),
+                ("ө", "o`"),
+                ("ү", "u`"),
+            ],
+        ),
+        Language::Az => alphabet(
+            "й",
+            &[
+                ("Ғ", "G`"),
+                ("Ә", "A`"),
+                ("Ј", "J"),
+                ("Ҝ", "K`"),
+                ("Ө", "O`"),
+                ("Ү", "U`"),
+                ("Һ", "H"),
+                ("Ҹ", "Ch`"),
+                ("ғ", "g`"),
+                ("ә", "a`"),
+                ("ј", "j"),
+                ("ҝ", "k`"),
+                ("ө", "o`"),
+                ("ү", "u`"),
+                ("һ", "h"),
+                ("ҹ", "ch`"),
+            ],
+        ),
+        Language::Mn => alphabet("", &[("Ө", "O`"), ("Ү", "U`"), ("ө", "o`"), ("ү", "u`")]),
+        Language::Bua => alphabet(
+            "",
+            &[
+                ("Ө", "O`"),
+                ("Ү", "U`"),
+                ("Һ", "H"),
+                ("ө", "o`"),
+                ("ү", "u`"),
+                ("һ", "h"),
+            ],
+        ),
+        Language::Md => alphabet("", &[("Ӂ", "Zh`"), ("ӂ", "zh`")]),
+        Language::Ab => alphabet(
+            "",
+            &[
+                ("Ә", "A`"),
+                ("Ҕ", "G`"),
+                ("Ӡ", "Dz`"),
+                ("Қ", "K`"),
+                ("Ҟ", "K``"),
+                ("Ҧ", "P`"),
+                ("Ҭ", "T`"),
+                ("Ҩ", "O`"),
+                ("Ҳ", "X`"),
+                ("Ҵ", "C`"),
+                ("Ҽ", "Ch`"),
+                ("Ҿ", "Ch``"),
+                ("Џ", "Dh"),
+                ("ә", "a`"),
+                ("ҕ", "g`"),
+                ("ӡ", "dz`"),
+                ("қ", "k`"),
+                ("ҟ", "k``"),
+                ("ҧ", "p`"),
+                ("ҭ", "t`"),
+                ("ҩ", "o`"),
+                ("ҳ", "x`"),
+                ("ҵ", "c`"),
+                ("ҽ", "ch`"),
+                ("ҿ", "ch``"),
+                ("џ", "dh"),
+            ],
+        ),
+        Language::Ba => alphabet(
+            "",
+            &[
+                ("Ә", "A`"),
+                ("Ғ", "G`"),
+                ("Ҙ", "Z`"),
+                ("Ҡ", "K`"),
+                ("Ң", "N`"),
+                ("Ө", "O`"),
+                ("Ҫ", "S`"),
+                ("Ү", "U`"),
+                ("Һ", "H"),
+                ("ә", "a`"),
+                ("ғ", "g`"),
+                ("ҙ", "z`"),
+                ("ҡ", "k`"),
+                ("ң", "n`"),
+                ("ө", "o`"),
+                ("ҫ", "s`"),
+                ("ү", "u`"),
+                ("һ", "h"),
+            ],
+        ),
+        Language::Tt => alphabet(
+            "",
+            &[
+                ("Ә", "A`"),
+                ("Җ", "Zh`"),
+                ("Ң", "N`"),
+                ("Ө", "O`"),
+                ("Ү", "U`"),
+                ("Һ", "H"),
+                ("ә", "a`"),
+                ("җ", "zh`"),
+                ("ң", "n`"),
+                ("ө", "o`"),
+                ("ү", "u`"),
+                ("һ", "h"),
+            ],
+        ),
+        Language::Cv => alphabet(
+            "",
+            &[
+                ("Ӑ", "A`"),
+                ("Ӗ", "E``"),
+                ("Ҫ", "S`"),
+                ("Ӳ", "U`"),
+                ("ӑ", "a`"),
+                ("ӗ", "e``"),
+                ("ҫ", "s`"),
+                ("ӳ", "u`"),
+            ],
+        ),
+        Language::Sah => alphabet(
+            "",
+            &[
+                ("Ҕ", "G`"),
+                ("Ҥ", "N`"),
+                ("Ө", "O`"),
+                ("Һ", "H"),
+                ("Ү", "U`"),
+                ("ҕ", "g`"),
+                ("ҥ", "n`"),
+                ("ө", "o`"),
+                ("һ", "h"),
+                ("ү", "u`"),
+            ],
+        ),
+        Language::Alt => alphabet(
+            "",
+            &[
+                ("Ј", "J`"),
+                ("Ҥ", "N`"),
+                ("Ӧ", "O`"),
+                ("Ӱ", "U`"),
+                ("ј", "j`"),
+                ("ҥ", "n`"),
+                ("ӧ", "o`"),
+                ("ӱ", "u`"),
+            ],
+        ),
+        Language::Chm => alphabet(
+            "",
+            &[
+                ("Ӓ", "A`"),
+                ("Ҥ", "N`"),
+                ("Ӧ", "O`"),
+                ("Ӱ", "U`"),
+                ("Ӹ", "Y``"),
+                ("ӓ", "a`"),
+                ("ҥ", "n`"),
+                ("ӧ", "o`"),
+                ("ӱ", "u`"),
+                ("ӹ", "y``"),
+            ],
+        ),
+        Language::Udm => alphabet(
+            "",
+            &[
+                ("Ӝ", "Zh`"),
+                ("Ӟ", "Z`"),
+                ("Ӥ", "I`"),
+                ("Ӧ", "O`"),
+                ("Ӵ", "Ch`"),
+                ("ӝ", "zh`"),
+                ("ӟ", "z`"),
+                ("ӥ", "i`"),
+                ("ӧ", "o`"),
+                ("ӵ", "ch`"),
+            ],
+        ),
+    }
+}
+
+/// The modern Russian letters without the `removed` ones (listed in lowercase)
+/// and with the `letters` of the language, which replace the Russian letters with the same source.
+fn alphabet(removed: &str, letters: &[(&'static str, &'static str)]) -> CharsMapping {
+    let mut table: CharsMapping = gost779b_ru()
+        .into_iter()
+        .filter(|&(source, _)| {
+            let lower = source.to_lowercase();
+            !removed.contains(&lower) && !letters.iter().any(|&(letter, _)| letter == source)
+        })
+        .collect();
+    table.extend_from_slice(letters);
+    table
+}
+
+/// Cyrillic transliteration table for all languages of the standard.
+/// implementation GOST 7.79 System A, ISO 9:1995.
+/// more details:
+/// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9)
+///
+/// Every letter has its own Latin letter with diacritics, in the composed form (NFC)
+/// where Unicode has one, so the transliteration can be reversed without loss.
+/// The table covers Russian, Belarusian, Ukrainian, Bulgarian, Macedonian, Serbian,
+/// the pre-reform and Old Slavonic letters and the Cyrillic alphabets of the non-Slavic
+/// languages: Abkhaz, Altai, Bashkir, Buryat, Chuvash, Kazakh, Khakas, Khanty, Kyrgyz,
+/// Mari, Moldovan, Mongolian, Ossetian, Tajik, Tatar, Udmurt, Uzbek, Yakut and others.
+pub fn gost779a() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ё", "Ë"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Й", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "H"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Ш", "Š"),
+        ("Щ", "Ŝ"),
+        ("Ы", "Y"),
+        ("Э", "È"),
+        ("Ю", "Û"),
+        ("Я", "Â"),
+        ("Ў", "Ǔ"),
+        ("Ґ", "G\u{300}"),
+        ("Є", "Ê"),
+        ("І", "Ì"),
+        ("Ї", "Ï"),
+        ("Ѓ", "Ǵ"),
+        ("Ѕ", "Ẑ"),
+        ("Ј", "J\u{30c}"),
+        ("Ќ", "Ḱ"),
+        ("Љ", "L\u{302}"),
+        ("Њ", "N\u{302}"),
+        ("Џ", "D\u{302}"),
+        ("Ђ", "Đ"),
+        ("Ћ", "Ć"),
+        ("Ѣ", "Ě"),
+        ("Ѳ", "F\u{300}"),
+        ("Ѵ", "Ỳ"),
+        ("Ѫ", "Ǎ"),
+        ("Ӑ", "Ă"),
+        ("Ӓ", "Ä"),
+        ("Ә", "A\u{30b}"),
+        ("Ӕ", "Æ"),
+        ("Ғ", "Ġ"),
+        ("Ҕ", "Ğ"),
+        ("Һ", "Ḥ"),
+        ("Ӗ", "Ĕ"),
+        ("Ӝ", "Z\u{304}"),
+        ("Ӂ", "Z\u{306}"),
+        ("Ӟ", "Z\u{308}"),
+        ("Ӡ", "Ź"),
+        ("Ӣ", "Ī"),
+        ("Ӥ", "Î"),
+        ("Ӄ", "Ḳ"),
+        ("Ҝ", "K\u{302}"),
+        ("Ҡ", "Ǩ"),
+        ("Ҟ", "K\u{304}"),
+        ("Қ", "K\u{326}"),
+        ("Ң", "N\u{326}"),
+        ("Ӊ", "Ṇ"),
+        ("Ҥ", "Ṅ"),
+        ("Ӧ", "Ö"),
+        ("Ө", "Ô"),
+        ("Ӫ", "Ő"),
+        ("Ҧ", "Ṕ"),
+        ("Ҫ", "Ș"),
+        ("Ҭ", "Ţ"),
+        ("Ӯ", "Ū"),
+        ("Ӱ", "Ü"),
+        ("Ӳ", "Ű"),
+        ("Ү", "Ù"),
+        ("Ұ", "U\u{307}"),
+        ("Ҳ", "Ḩ"),
+        ("Ҵ", "C\u{304}"),
+        ("Ӵ", "C\u{308}"),
+        ("Ҷ", "Ç"),
+        ("Ӌ", "C\u{323}"),
+        ("Ҹ", "Ĉ"),
+        ("Ҩ", "Ò"),
+        ("Ӹ", "Ÿ"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ё", "ë"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "i"),
+        ("й", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "h"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("ш", "š"),
+        ("щ", "ŝ"),
+        ("ъ", "ʺ"),
+        ("ы", "y"),
+        ("ь", "ʹ"),
+        ("э", "è"),
+        ("ю", "û"),
+        ("я", "â"),
+        ("ў", "ǔ"),
+        ("ґ", "g\u{300}"),
+        ("є", "ê"),
+        ("і", "ì"),
+        ("ї", "ï"),
+        ("ѓ", "ǵ"),
+        ("ѕ", "ẑ"),
+        ("ј", "ǰ"),
+        ("ќ", "ḱ"),
+        ("љ", "l\u{302}"),
+        ("њ", "n\u{302}"),
+        ("џ", "d\u{302}"),
+        ("ђ", "đ"),
+        ("ћ", "ć"),
+        ("ѣ", "ě"),
+        ("ѳ", "f\u{300}"),
+        ("ѵ", "ỳ"),
+        ("ѫ", "ǎ"),
+        ("ӑ", "ă"),
+        ("ӓ", "ä"),
+        ("ә", "a\u{30b}"),
+        ("ӕ", "æ"),
+        ("ғ", "ġ"),
+        ("ҕ", "ğ"),
+        ("һ", "ḥ"),
+        ("ӗ", "ĕ"),
+        ("ӝ", "z\u{304}"),
+        ("ӂ", "z\u{306}"),
+        ("ӟ", "z\u{308}"),
+        ("ӡ", "ź"),
+        ("ӣ", "ī"),
+        ("ӥ", "î"),
+        ("ӄ", "ḳ"),
+        ("ҝ", "k\u{302}"),
+        ("ҡ", "ǩ"),
+        ("ҟ", "k\u{304}"),
+        ("қ", "k\u{326}"),
+        ("ң", "n\u{326}"),
+        ("ӊ", "ṇ"),
+        ("ҥ", "ṅ"),
+        ("ӧ", "ö"),
+        ("ө", "ô"),
+        ("ӫ", "ő"),
+        ("ҧ", "ṕ"),
+        ("ҫ", "ș"),
+        ("ҭ", "ţ"),
+        ("ӯ", "ū"),
+        ("ӱ", "ü"),
+        ("ӳ", "ű"),
+        ("ү", "ù"),
+        ("ұ", "u\u{307}"),
+        ("ҳ", "ḩ"),
+        ("ҵ", "c\u{304}"),
+        ("ӵ", "c\u{308}"),
+        ("ҷ", "ç"),
+        ("ӌ", "c\u{323}"),
+        ("ҹ", "ĉ"),
+        ("ҩ", "ò"),
+        ("ӹ", "ÿ"),
+        ("Ъ", "ʺ"),
+        ("Ь", "ʹ"),
+        ("Ӏ", "‡"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
@@ -0,0 +1,140 @@
+use crate::{Condition, Rule};
+
+/// Kazakh Latin alphabet of 2021.
+///
+/// more details:
+/// [Kazakh alphabets](https://en.wikipedia.org/wiki/Kazakh_alphabets#Latin_alphabet_of_2021)
+///
+/// Both `и` and `й` are `i` with the capital `İ` whatever the vowels around them,
+/// `і` is the dotless `ı` with the capital `I`. The letters of Russian loanwords
+/// are written as they are pronounced:
+///
+/// * the endings `ий` and `ый` become `i` and `y`;
+/// * `ц` is `ts` after a vowel and `s` otherwise: "Sirk", "Otets";
+/// * `щ` is `şş` between vowels and `ş` otherwise: "Şuka", "İaşşik", "Borş";
+/// * `ё`, `ю`, `я` are `o`, `u`, `a` after `ж`, `ш`, `ч`, `щ` and `io`, `iu`, `ia`
+///   otherwise: "Broşura", "İolka";
+/// * the signs `ъ` and `ь` are `i` before `е` and are dropped otherwise: "Obiekt", "Palto";
+/// * `э` is `e`.
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn kazakh_2021() -> Vec<Rule> {
+    let word_end = Condition::WordBoundary;
+    let vowel = Condition::OneOf("аәеёиоөуұүыіэюя");
+    let hushing = Condition::OneOf("жшчщ");
+    let e = Condition::OneOf("е");
+
+    let mut rules = vec![
+        Rule::new("ИЙ", "İ").after(word_end),
+        Rule::new("Ий", "İ").after(word_end),
+        Rule::new("ий", "i").after(word_end),
+        Rule::new("ый", "y").after(word_end),
+        Rule::new("Ц", "Ts").before(vowel),
+        Rule::new("ц", "ts").before(vowel),
+        Rule::new("Щ", "Şş").before(vowel).after(vowel),
+        Rule::new("щ", "şş").before(vowel).after(vowel),
+        Rule::new("Ё", "O").before(hushing),
+        Rule::new("ё", "o").before(hushing),
+        Rule::new("Ю", "U").before(hushing),
+        Rule::new("ю", "u").before(hushing),
+        Rule::new("Я", "A").before(hushing),
+        Rule::new("я", "a").before(hushing),
+        Rule::new("Ъ", "İ").after(e),
+        Rule::new("ъ", "i").after(e),
+        Rule::new("Ь", "İ").after(e),
+        Rule::new("ь", "i").after(e),
+    ];
+
+    rules.extend(
+        [
+            ("А", "A"),
+            ("Ә", "Ä"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "G"),
+            ("Ғ", "Ğ"),
+            ("Д", "D"),
+            ("Е", "E"),
+            ("Ё", "İo"),
+            ("Ж", "J"),
+            ("З", "Z"),
+            ("И", "İ"),
+            ("Й", "İ"),
+            ("К", "K"),
+            ("Қ", "Q"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("Ң", "Ñ"),
+            ("О", "O"),
+            ("Ө", "Ö"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ұ", "Ū"),
+            ("Ү", "Ü"),
+            ("Ф", "F"),
+            ("Х", "H"),
+            ("Һ", "H"),
+            ("Ц", "S"),
+            ("Ч", "Ç"),
+            ("Ш", "Ş"),
+            ("Щ", "Ş"),
+            ("Ъ", ""),
+            ("Ы", "Y"),
+            ("І", "I"),
+            ("Ь", ""),
+            ("Э", "E"),
+            ("Ю", "İu"),
+            ("Я", "İa"),
+            ("а", "a"),
+            ("ә", "ä"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "g"),
+            ("ғ", "ğ"),
+            ("д", "d"),
+            ("е", "e"),
+            ("ё", "io"),
+            ("ж", "j"),
+            ("з", "z"),
+            ("и", "i"),
+            ("й", "i"),
+            ("к", "k"),
+            ("қ", "q"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("ң", "ñ"),
+            ("о", "o"),
+            ("ө", "ö"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ұ", "ū"),
+            ("ү", "ü"),
+            ("ф", "f"),
+            ("х", "h"),
+            ("һ", "h"),
+            ("ц", "s"),
+            ("ч", "ç"),
+            ("ш", "ş"),
+            ("щ", "ş"),
+            ("ъ", ""),
+            ("ы", "y"),
+            ("і", "ı"),
+            ("ь", ""),
+            ("э", "e"),
+            ("ю", "iu"),
+            ("я", "ia"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
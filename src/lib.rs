@@ -1,18 +1,59 @@
+// The tables are written as `[...].iter().cloned().collect()`.
+#![allow(clippy::iter_cloned_collect)]
+
+mod ala_lc;
+mod alignment;
+mod analysis;
+mod bgn_pcgn;
+mod builder;
 mod bulgarian;
+mod candidates;
+mod casing;
+mod detect;
+mod error;
 mod gost779;
+mod kazakh;
 mod macedonian;
+mod mongolian;
+mod name_match;
+mod ngram;
 mod order_n_995;
 mod passport2013;
+mod scholarly;
+mod script;
+mod serbian;
+mod stream;
 mod transliterator;
+mod trie;
+mod ukrainian;
+mod unmapped;
+mod uzbek;
 
 #[cfg(test)]
 mod tests;
 
+pub use ala_lc::*;
+pub use alignment::*;
+pub use analysis::*;
+pub use bgn_pcgn::*;
+pub use builder::*;
 pub use bulgarian::*;
+pub use candidates::*;
+pub use detect::*;
+pub use error::*;
 pub use gost779::*;
+pub use kazakh::*;
 pub use macedonian::*;
+pub use mongolian::*;
+pub use name_match::*;
+pub use ngram::*;
 pub use order_n_995::*;
 pub use passport2013::*;
+pub use scholarly::*;
+pub use serbian::*;
 pub use transliterator::*;
+pub use ukrainian::*;
+pub use unmapped::*;
+pub use uzbek::*;
 
 pub type CharsMapping = Vec<(&'static str, &'static str)>;
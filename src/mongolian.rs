@@ -0,0 +1,109 @@
+use super::CharsMapping;
+
+/// Cyrillic Mongolian transliteration table.
+/// implementation MNS 5217:2012, the national romanization of Mongolia.
+/// more details:
+/// [Romanization of Mongolian](https://en.wikipedia.org/wiki/Romanization_of_Mongolian)
+///
+/// `и` and `й` are both `i`, `ш` and `щ` are both `sh`, the signs `ъ` and `ь` are `i`.
+pub fn mns_5217() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Е", "Ye"),
+        ("Ё", "Yo"),
+        ("Ж", "J"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Й", "I"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("Ө", "Ö"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ү", "Ü"),
+        ("Ф", "F"),
+        ("Х", "Kh"),
+        ("Ц", "Ts"),
+        ("Ч", "Ch"),
+        ("Ш", "Sh"),
+        ("Щ", "Sh"),
+        ("Ъ", "I"),
+        ("Ы", "Y"),
+        ("Ь", "I"),
+        ("Э", "E"),
+        ("Ю", "Yu"),
+        ("Я", "Ya"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("е", "ye"),
+        ("ё", "yo"),
+        ("ж", "j"),
+        ("з", "z"),
+        ("и", "i"),
+        ("й", "i"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("ө", "ö"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ү", "ü"),
+        ("ф", "f"),
+        ("х", "kh"),
+        ("ц", "ts"),
+        ("ч", "ch"),
+        ("ш", "sh"),
+        ("щ", "sh"),
+        ("ъ", "i"),
+        ("ы", "y"),
+        ("ь", "i"),
+        ("э", "e"),
+        ("ю", "yu"),
+        ("я", "ya"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// The MNS 5217:2012 table in ASCII: `ө` is `o` and `ү` is `u`, as in Mongolian passports.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{mns_5217_ascii, Transliterator};
+/// let trasliterator = Transliterator::new(mns_5217_ascii());
+/// assert_eq!("Ulaanbaatar, Olgii", trasliterator.convert("Улаанбаатар, Өлгий", false));
+///
+/// ```
+pub fn mns_5217_ascii() -> CharsMapping {
+    mns_5217()
+        .into_iter()
+        .map(|(source, translit)| match translit {
+            "Ö" => (source, "O"),
+            "ö" => (source, "o"),
+            "Ü" => (source, "U"),
+            "ü" => (source, "u"),
+            _ => (source, translit),
+        })
+        .collect()
+}
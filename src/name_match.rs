@@ -0,0 +1,373 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::detect::Standard;
+use crate::transliterator::Language;
+
+/// The standards whose tables are used for the matching of names.
+const STANDARDS: &[Standard] = &[
+    Standard::Passport2013,
+    Standard::OrderN995,
+    Standard::Gost779B(Language::Ru),
+    Standard::Gost779B(Language::Ua),
+    Standard::Gost779B(Language::By),
+];
+
+/// Spellings which are common in documents but belong to no table.
+const COMMON: &[(&str, &str)] = &[
+    ("х", "h"),
+    ("х", "kh"),
+    ("х", "x"),
+    ("ю", "yu"),
+    ("ю", "ju"),
+    ("ю", "iu"),
+    ("я", "ya"),
+    ("я", "ja"),
+    ("я", "ia"),
+    ("ё", "yo"),
+    ("ё", "jo"),
+    ("ё", "io"),
+    ("ё", "e"),
+    ("е", "ye"),
+    ("й", "y"),
+    ("й", "j"),
+    ("й", "i"),
+    ("ц", "ts"),
+    ("ц", "tz"),
+    ("ц", "c"),
+    ("щ", "shch"),
+    ("щ", "sch"),
+    ("ы", "y"),
+    ("ы", "i"),
+    ("э", "e"),
+    ("ъ", "ie"),
+    ("ь", "'"),
+    ("ъ", "'"),
+    ("кс", "x"),
+    ("ий", "y"),
+    ("ий", "iy"),
+    ("ий", "i"),
+    ("ый", "y"),
+    ("ый", "yi"),
+];
+
+/// The confidence kept by a spelling of another standard.
+const OTHER_STANDARD: f64 = 0.95;
+/// The confidence kept by a common spelling.
+const COMMON_SPELLING: f64 = 0.9;
+/// The confidence kept by an omitted sign.
+const OMITTED: f64 = 0.95;
+/// The confidence kept by a letter which does not match.
+const MISMATCH: f64 = 0.5;
+
+/// The result of [`match_name`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameMatch {
+    /// The confidence that the names are the same, from 0 to 1.
+    pub confidence: f64,
+    /// The standard which explains the Latin name best.
+    pub standard: Standard,
+    /// The differences from the spelling of the standard.
+    pub variations: Vec<Variation>,
+}
+
+impl NameMatch {
+    /// Checks that every letter of the Latin name is a known spelling
+    /// of the Cyrillic one, even if the standards are mixed.
+    pub fn is_match(&self) -> bool {
+        self.variations
+            .iter()
+            .all(|variation| variation.kind != VariationKind::Mismatch)
+    }
+}
+
+impl fmt::Display for NameMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, confidence {:.2}", self.standard, self.confidence)?;
+        for (i, variation) in self.variations.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { ", " };
+            write!(f, "{}{}", separator, variation)?;
+        }
+        Ok(())
+    }
+}
+
+/// A difference of the Latin name from the spelling of a standard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variation {
+    /// The byte offset in the Latin name.
+    pub offset: usize,
+    /// The Cyrillic letters, empty if the Latin name has extra letters.
+    pub source: String,
+    /// The Latin letters, empty if the Cyrillic letters are omitted.
+    pub translit: String,
+    /// The kind of the difference.
+    pub kind: VariationKind,
+}
+
+impl fmt::Display for Variation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} as {:?} ", self.source, self.translit)?;
+        match self.kind {
+            VariationKind::Standard(standard) => write!(f, "({})", standard),
+            VariationKind::Common => f.write_str("(common spelling)"),
+            VariationKind::Omitted => f.write_str("(omitted)"),
+            VariationKind::Mismatch => f.write_str("(mismatch)"),
+        }
+    }
+}
+
+/// The kinds of [`Variation`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariationKind {
+    /// The letter is spelled as in another standard.
+    Standard(Standard),
+    /// A spelling common in documents, like "H" for `х` or "Y" for `ий`.
+    Common,
+    /// The letter is omitted, like `ь`.
+    Omitted,
+    /// The letters do not match.
+    Mismatch,
+}
+
+/// Decides whether the Latin name is a romanization of the Cyrillic name.
+///
+/// The name is compared letter by letter with the spelling of each of
+/// [`Passport2013`](crate::Passport2013), [`OrderN995`](crate::OrderN995) and
+/// [`Gost779B`](crate::Gost779B). Spellings of the other standards, common spellings
+/// and omitted signs lower the confidence a little, other differences a lot.
+/// The case is ignored.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{match_name, Standard, VariationKind};
+/// let result = match_name("Юрий Хрущёв", "Yuriy Hrushchev");
+///
+/// assert!(result.is_match());
+/// assert_eq!(Standard::Passport2013, result.standard);
+/// assert!(result
+///     .variations
+///     .iter()
+///     .any(|variation| variation.translit == "H" && variation.kind == VariationKind::Common));
+/// assert!(result.confidence > 0.7);
+///
+/// assert!(!match_name("Юрий", "Yuras").is_match());
+///
+/// ```
+pub fn match_name(cyrillic: &str, latin: &str) -> NameMatch {
+    let tables = tables();
+
+    let mut best: Option<NameMatch> = None;
+    for (index, &standard) in STANDARDS.iter().enumerate() {
+        let result = align(cyrillic, latin, index, tables);
+        let result = NameMatch {
+            confidence: result.0,
+            standard,
+            variations: result.1,
+        };
+        if best
+            .as_ref()
+            .is_none_or(|best| result.confidence > best.confidence)
+        {
+            best = Some(result);
+        }
+    }
+
+    // `STANDARDS` is not empty
+    best.unwrap_or(NameMatch {
+        confidence: 0.0,
+        standard: Standard::Passport2013,
+        variations: Vec::new(),
+    })
+}
+
+/// The lowercase Cyrillic strings and the Latin strings of the rules of `STANDARDS`, built once.
+fn tables() -> &'static [Vec<(String, &'static str)>] {
+    static TABLES: OnceLock<Vec<Vec<(String, &'static str)>>> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        STANDARDS
+            .iter()
+            .map(|standard| {
+                standard
+                    .transliterator()
+                    .expect("the standards of names have a table")
+                    .rules()
+                    .iter()
+                    .map(|rule| (rule.source.to_lowercase(), rule.translit))
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+/// A way to read Latin letters at a position of the Cyrillic name.
+struct Step {
+    confidence: f64,
+    from: (usize, usize),
+    kind: Option<VariationKind>,
+}
+
+/// Finds the most confident alignment of the names relative to the standard `base`.
+fn align(
+    cyrillic: &str,
+    latin: &str,
+    base: usize,
+    tables: &[Vec<(String, &'static str)>],
+) -> (f64, Vec<Variation>) {
+    let source: Vec<(usize, char)> = cyrillic.char_indices().collect();
+    let target: Vec<(usize, char)> = latin.char_indices().collect();
+    let lower: Vec<char> = source.iter().map(|&(_, c)| lowercase(c)).collect();
+    let latin_lower: Vec<char> = target.iter().map(|&(_, c)| lowercase(c)).collect();
+    let (n, m) = (source.len(), target.len());
+
+    let mut steps: Vec<Vec<Option<Step>>> =
+        (0..=n).map(|_| (0..=m).map(|_| None).collect()).collect();
+    steps[0][0] = Some(Step {
+        confidence: 1.0,
+        from: (0, 0),
+        kind: None,
+    });
+
+    for i in 0..=n {
+        let options = spellings(&lower[i..], base, tables);
+        for j in 0..=m {
+            let Some(confidence) = steps[i][j].as_ref().map(|step| step.confidence) else {
+                continue;
+            };
+            let mut relax = |to: (usize, usize), factor: f64, kind: Option<VariationKind>| {
+                let confidence = confidence * factor;
+                let step = &mut steps[to.0][to.1];
+                if step
+                    .as_ref()
+                    .is_none_or(|step| confidence > step.confidence)
+                {
+                    *step = Some(Step {
+                        confidence,
+                        from: (i, j),
+                        kind,
+                    });
+                }
+            };
+
+            for (len, translit, factor, kind) in &options {
+                let count = translit.chars().count();
+                if latin_lower[j..].len() >= count
+                    && translit
+                        .chars()
+                        .eq(latin_lower[j..j + count].iter().copied())
+                {
+                    relax((i + len, j + count), *factor, *kind);
+                }
+            }
+
+            if i < n && j < m && !lower[i].is_alphabetic() && lower[i] == latin_lower[j] {
+                relax((i + 1, j + 1), 1.0, None);
+            }
+            if i < n && j < m {
+                relax((i + 1, j + 1), MISMATCH, Some(VariationKind::Mismatch));
+            }
+            if i < n {
+                relax((i + 1, j), MISMATCH, Some(VariationKind::Mismatch));
+            }
+            if j < m {
+                relax((i, j + 1), MISMATCH, Some(VariationKind::Mismatch));
+            }
+        }
+    }
+
+    let mut variations = Vec::new();
+    let (mut i, mut j) = (n, m);
+    let confidence = steps[n][m].as_ref().map_or(0.0, |step| step.confidence);
+    while (i, j) != (0, 0) {
+        let Some(step) = steps[i][j].as_ref() else {
+            break;
+        };
+        let (from_i, from_j) = step.from;
+        if let Some(kind) = step.kind {
+            let start = |chars: &[(usize, char)], from: usize, to: usize, len: usize| {
+                let begin = chars.get(from).map_or(len, |&(offset, _)| offset);
+                let end = chars.get(to).map_or(len, |&(offset, _)| offset);
+                (begin, end)
+            };
+            let (source_start, source_end) = start(&source, from_i, i, cyrillic.len());
+            let (latin_start, latin_end) = start(&target, from_j, j, latin.len());
+            variations.push(Variation {
+                offset: latin_start,
+                source: cyrillic[source_start..source_end].to_owned(),
+                translit: latin[latin_start..latin_end].to_owned(),
+                kind,
+            });
+        }
+        i = from_i;
+        j = from_j;
+    }
+    variations.reverse();
+
+    (confidence, variations)
+}
+
+/// The Latin spellings of the Cyrillic letters at the start of `rest`:
+/// the number of letters, the spelling, the confidence kept and the kind of the variation.
+fn spellings(
+    rest: &[char],
+    base: usize,
+    tables: &[Vec<(String, &'static str)>],
+) -> Vec<(usize, String, f64, Option<VariationKind>)> {
+    let mut spellings: Vec<(usize, String, f64, Option<VariationKind>)> = Vec::new();
+    let mut add = |len: usize, translit: String, factor: f64, kind: Option<VariationKind>| {
+        let known = spellings
+            .iter()
+            .any(|(other_len, other, _, _)| *other_len == len && *other == translit);
+        if !known {
+            spellings.push((len, translit, factor, kind));
+        }
+    };
+    let starts = |source: &str| {
+        let len = source.chars().count();
+        (rest.len() >= len && source.chars().eq(rest[..len].iter().copied())).then_some(len)
+    };
+
+    // the base standard first, so its spellings are not counted as variations
+    let order = std::iter::once(base).chain((0..tables.len()).filter(|&index| index != base));
+    for index in order {
+        for (source, translit) in &tables[index] {
+            if let Some(len) = starts(source) {
+                let (factor, kind) = if index == base {
+                    (1.0, None)
+                } else if translit.is_empty() {
+                    (OMITTED, Some(VariationKind::Omitted))
+                } else {
+                    (
+                        OTHER_STANDARD,
+                        Some(VariationKind::Standard(STANDARDS[index])),
+                    )
+                };
+                add(len, translit.to_lowercase(), factor, kind);
+            }
+        }
+    }
+
+    for &(source, translit) in COMMON {
+        if let Some(len) = starts(source) {
+            add(
+                len,
+                translit.to_owned(),
+                COMMON_SPELLING,
+                Some(VariationKind::Common),
+            );
+        }
+    }
+    for sign in ["ь", "ъ"] {
+        if let Some(len) = starts(sign) {
+            add(len, String::new(), OMITTED, Some(VariationKind::Omitted));
+        }
+    }
+
+    spellings
+}
+
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::transliterator::{is_apostrophe, words};
+
+/// The character which stands for the start and the end of a word.
+const BOUNDARY: char = ' ';
+
+/// The number of letters assumed for the smoothing of unseen letters.
+const ALPHABET_SIZE: f64 = 64.0;
+
+/// Letter trigram statistics of a language, used to rank the candidates
+/// of the transliteration from the Latin alphabet.
+///
+/// Letters are compared in lowercase, apostrophes are parts of words
+/// and everything else is a word boundary. Unseen trigrams get a part
+/// of the probability of the bigram and the letter (Witten–Bell smoothing).
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::LetterModel;
+/// let model = LetterModel::russian();
+/// assert!(model.log_prob("ёлка") > model.log_prob("ёлкы"));
+///
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LetterModel {
+    trigrams: HashMap<[char; 3], u32>,
+    bigrams: HashMap<[char; 2], u32>,
+    unigrams: HashMap<char, u32>,
+    /// The number of trigrams and of different next letters after two letters.
+    long_histories: HashMap<[char; 2], History>,
+    /// The number of bigrams and of different next letters after a letter.
+    short_histories: HashMap<char, History>,
+    total: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct History {
+    count: u32,
+    followers: u32,
+}
+
+impl LetterModel {
+    /// Collects the statistics of a sample text.
+    pub fn from_text(text: &str) -> Self {
+        let mut model = LetterModel::default();
+
+        for (_, word) in words(text).filter(|&(in_word, _)| in_word) {
+            let mut context = [BOUNDARY; 2];
+            for c in word.chars().map(normalize).chain(Some(BOUNDARY)) {
+                model.add([context[0], context[1], c], 1);
+                context = [context[1], c];
+            }
+        }
+
+        model
+    }
+
+    /// Collects the statistics of a table of trigram counts, one trigram and its count
+    /// per line, like "вод 12". The character `_` stands for the start and the end
+    /// of a word: "__в" is `в` at the start of a word.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::LetterModel;
+    /// let model = LetterModel::from_trigrams("__э 3\n_эх 3\nэхо 2\nхо_ 2\nэхэ 1\nхэ_ 1");
+    /// assert!(model.log_prob("эхо") > model.log_prob("ехо"));
+    ///
+    /// ```
+    pub fn from_trigrams(table: &str) -> Self {
+        let mut model = LetterModel::default();
+
+        for line in table.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(trigram), Some(count)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let chars: Vec<char> = trigram
+                .chars()
+                .map(|c| if c == '_' { BOUNDARY } else { normalize(c) })
+                .collect();
+            if let (&[a, b, c], Ok(count)) = (chars.as_slice(), count.parse()) {
+                model.add([a, b, c], count);
+            }
+        }
+
+        model
+    }
+
+    /// The statistics of Russian.
+    pub fn russian() -> &'static LetterModel {
+        static MODEL: OnceLock<LetterModel> = OnceLock::new();
+        MODEL.get_or_init(|| LetterModel::from_trigrams(TRIGRAMS_RU))
+    }
+
+    /// The statistics of Ukrainian.
+    pub fn ukrainian() -> &'static LetterModel {
+        static MODEL: OnceLock<LetterModel> = OnceLock::new();
+        MODEL.get_or_init(|| LetterModel::from_trigrams(TRIGRAMS_UA))
+    }
+
+    /// The statistics of Bulgarian.
+    pub fn bulgarian() -> &'static LetterModel {
+        static MODEL: OnceLock<LetterModel> = OnceLock::new();
+        MODEL.get_or_init(|| LetterModel::from_trigrams(TRIGRAMS_BG))
+    }
+
+    /// The statistics of Macedonian.
+    pub fn macedonian() -> &'static LetterModel {
+        static MODEL: OnceLock<LetterModel> = OnceLock::new();
+        MODEL.get_or_init(|| LetterModel::from_trigrams(TRIGRAMS_MK))
+    }
+
+    /// Counts the trigram `count` times, with its bigram and its last letter.
+    fn add(&mut self, trigram: [char; 3], count: u32) {
+        let [a, b, c] = trigram;
+
+        let seen = self.trigrams.entry(trigram).or_default();
+        let history = self.long_histories.entry([a, b]).or_default();
+        history.count += count;
+        history.followers += u32::from(*seen == 0);
+        *seen += count;
+
+        let seen = self.bigrams.entry([b, c]).or_default();
+        let history = self.short_histories.entry(b).or_default();
+        history.count += count;
+        history.followers += u32::from(*seen == 0);
+        *seen += count;
+
+        *self.unigrams.entry(c).or_default() += count;
+        self.total += count;
+    }
+
+    /// The natural logarithm of the probability of the text, higher is more likely.
+    pub fn log_prob(&self, text: &str) -> f64 {
+        let mut context = [BOUNDARY; 2];
+        let mut score = 0.0;
+
+        for c in text.chars().map(normalize).chain(Some(BOUNDARY)) {
+            if c == BOUNDARY && context[1] == BOUNDARY {
+                continue;
+            }
+            score += self.next_log_prob(context, c);
+            context = [context[1], c];
+        }
+
+        score
+    }
+
+    /// The natural logarithm of the probability of `c` after the two previous characters.
+    pub(crate) fn next_log_prob(&self, context: [char; 2], c: char) -> f64 {
+        let count = self.unigrams.get(&c).copied().unwrap_or(0);
+        let unigram = (f64::from(count) + 1.0) / (f64::from(self.total) + ALPHABET_SIZE);
+        let bigram = smooth(
+            self.bigrams.get(&[context[1], c]),
+            self.short_histories.get(&context[1]),
+            unigram,
+        );
+        let trigram = smooth(
+            self.trigrams.get(&[context[0], context[1], c]),
+            self.long_histories.get(&context),
+            bigram,
+        );
+
+        trigram.ln()
+    }
+}
+
+/// Interpolates the frequency after the history with the probability of a shorter history.
+fn smooth(count: Option<&u32>, history: Option<&History>, lower: f64) -> f64 {
+    match history {
+        Some(history) if history.count > 0 => {
+            let count = f64::from(count.copied().unwrap_or(0));
+            let followers = f64::from(history.followers);
+            (count + followers * lower) / (f64::from(history.count) + followers)
+        }
+        _ => lower,
+    }
+}
+
+/// Returns the lowercase letter, the apostrophe `'` for all apostrophes
+/// or the word boundary for other characters.
+pub(crate) fn normalize(c: char) -> char {
+    if c.is_alphabetic() {
+        c.to_lowercase().next().unwrap_or(c)
+    } else if is_apostrophe(c) {
+        '\''
+    } else {
+        BOUNDARY
+    }
+}
+
+pub(crate) fn start() -> [char; 2] {
+    [BOUNDARY; 2]
+}
+
+pub(crate) fn end() -> char {
+    BOUNDARY
+}
+
+// The trigram counts of the words of the Russian, Ukrainian, Bulgarian and Macedonian
+// translations of free software messages and of the country, subdivision and language
+// names (gettext catalogs of GNU, GNOME, systemd, iso-codes), trigrams seen once are left out.
+const TRIGRAMS_RU: &str = include_str!("../data/trigrams_ru.txt");
+const TRIGRAMS_UA: &str = include_str!("../data/trigrams_ua.txt");
+const TRIGRAMS_BG: &str = include_str!("../data/trigrams_bg.txt");
+const TRIGRAMS_MK: &str = include_str!("../data/trigrams_mk.txt");
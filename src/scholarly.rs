@@ -0,0 +1,557 @@
+use super::CharsMapping;
+use crate::bgn_pcgn::separators;
+use crate::{Language, Rule};
+
+/// Cyrillic transliteration rules of the scientific transliteration for the language,
+/// `None` if there is no table. The letters which would be read back as another letter
+/// are separated by "·": `шч` -> "š·č" (not `щ`), `йа` -> "j·a" (not `я`),
+/// `дз` -> "d·z" (not `ѕ`), so the text is restored from the Latin alphabet.
+pub fn scholarly_rules(lang: Language) -> Option<Vec<Rule>> {
+    let (table, letters): (_, &[_]) = match lang {
+        Language::Ru => (
+            scholarly_ru(),
+            &[
+                ("Й", "J·", "ау"),
+                ("Ш", "Š·", "ч"),
+                ("й", "j·", "ау"),
+                ("ш", "š·", "ч"),
+            ],
+        ),
+        Language::Ua => (
+            scholarly_ua(),
+            &[
+                ("Й", "J·", "аеіу"),
+                ("Ш", "Š·", "ч"),
+                ("й", "j·", "аеіу"),
+                ("ш", "š·", "ч"),
+            ],
+        ),
+        Language::By => (scholarly_by(), &[("Й", "J·", "ау"), ("й", "j·", "ау")]),
+        Language::Bg => (
+            scholarly_bg(),
+            &[
+                ("Й", "J·", "ау"),
+                ("Ш", "Š·", "т"),
+                ("й", "j·", "ау"),
+                ("ш", "š·", "т"),
+            ],
+        ),
+        Language::Mk => (
+            scholarly_mk(),
+            &[
+                ("Д", "D·", "зж"),
+                ("Л", "L·", "ј"),
+                ("Н", "N·", "ј"),
+                ("д", "d·", "зж"),
+                ("л", "l·", "ј"),
+                ("н", "n·", "ј"),
+            ],
+        ),
+        Language::Sr => (
+            scholarly_sr(),
+            &[
+                ("Д", "D·", "ж"),
+                ("Л", "L·", "ј"),
+                ("Н", "N·", "ј"),
+                ("д", "d·", "ж"),
+                ("л", "l·", "ј"),
+                ("н", "n·", "ј"),
+            ],
+        ),
+        _ => return None,
+    };
+
+    let mut rules = separators(letters);
+    rules.extend(table.into_iter().map(Rule::from));
+    Some(rules)
+}
+
+/// Cyrillic Russian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// The table includes the pre-reform letters `і`, `ѣ`, `ѳ`, `ѵ`.
+///
+/// [`scholarly_rules`] separates `йа`, `йу` and `шч` by "·",
+/// otherwise they are read back as `я`, `ю` and `щ`.
+pub fn scholarly_ru() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ё", "Ë"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Й", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "X"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Ш", "Š"),
+        ("Щ", "Šč"),
+        ("Ы", "Y"),
+        ("Э", "È"),
+        ("Ю", "Ju"),
+        ("Я", "Ja"),
+        ("І", "Ì"),
+        ("Ѣ", "Ě"),
+        ("Ѳ", "F\u{300}"),
+        ("Ѵ", "Ỳ"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ё", "ë"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "i"),
+        ("й", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "x"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("ш", "š"),
+        ("щ", "šč"),
+        ("ъ", "ʺ"),
+        ("Ъ", "ʺ"),
+        ("ы", "y"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("э", "è"),
+        ("ю", "ju"),
+        ("я", "ja"),
+        ("і", "ì"),
+        ("ѣ", "ě"),
+        ("ѳ", "f\u{300}"),
+        ("ѵ", "ỳ"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Ukrainian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// The apostrophe is kept.
+///
+/// [`scholarly_rules`] separates `йа`, `йе`, `йі`, `йу` and `шч` by "·",
+/// otherwise they are read back as `я`, `є`, `ї`, `ю` and `щ`.
+pub fn scholarly_ua() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "H"),
+        ("Ґ", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Є", "Je"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "Y"),
+        ("І", "I"),
+        ("Ї", "Ji"),
+        ("Й", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "X"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Ш", "Š"),
+        ("Щ", "Šč"),
+        ("Ю", "Ju"),
+        ("Я", "Ja"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "h"),
+        ("ґ", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("є", "je"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "y"),
+        ("і", "i"),
+        ("ї", "ji"),
+        ("й", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "x"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("ш", "š"),
+        ("щ", "šč"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("ю", "ju"),
+        ("я", "ja"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Belarusian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// The apostrophe is kept.
+///
+/// [`scholarly_rules`] separates `йа` and `йу` by "·",
+/// otherwise they are read back as `я` and `ю`.
+pub fn scholarly_by() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "H"),
+        ("Ґ", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ё", "Ë"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("І", "I"),
+        ("Й", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ў", "Ŭ"),
+        ("Ф", "F"),
+        ("Х", "X"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Ш", "Š"),
+        ("Ы", "Y"),
+        ("Э", "È"),
+        ("Ю", "Ju"),
+        ("Я", "Ja"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "h"),
+        ("ґ", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ё", "ë"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("і", "i"),
+        ("й", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ў", "ŭ"),
+        ("ф", "f"),
+        ("х", "x"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("ш", "š"),
+        ("ы", "y"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("э", "è"),
+        ("ю", "ju"),
+        ("я", "ja"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Bulgarian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// The table includes the pre-reform letters `ѣ` and `ѫ`.
+///
+/// [`scholarly_rules`] separates `йа`, `йу` and `шт` by "·",
+/// otherwise they are read back as `я`, `ю` and `щ`.
+pub fn scholarly_bg() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Е", "E"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Й", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "X"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Ш", "Š"),
+        ("Щ", "Št"),
+        ("Ъ", "Ă"),
+        ("Ю", "Ju"),
+        ("Я", "Ja"),
+        ("Ѣ", "Ě"),
+        ("Ѫ", "Ǎ"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("е", "e"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "i"),
+        ("й", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("м", "m"),
+        ("н", "n"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "x"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("ш", "š"),
+        ("щ", "št"),
+        ("ъ", "ă"),
+        ("ь", "ʹ"),
+        ("Ь", "ʹ"),
+        ("ю", "ju"),
+        ("я", "ja"),
+        ("ѣ", "ě"),
+        ("ѫ", "ǎ"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Macedonian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// [`scholarly_rules`] separates `дз`, `дж`, `лј` and `нј` by "·",
+/// otherwise they are read back as `ѕ`, `џ`, `љ` and `њ`.
+pub fn scholarly_mk() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Ѓ", "Ǵ"),
+        ("Е", "E"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("Ѕ", "Dz"),
+        ("И", "I"),
+        ("Ј", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("Љ", "Lj"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("Њ", "Nj"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("Ќ", "Ḱ"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "H"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Џ", "Dž"),
+        ("Ш", "Š"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("ѓ", "ǵ"),
+        ("е", "e"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("ѕ", "dz"),
+        ("и", "i"),
+        ("ј", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("љ", "lj"),
+        ("м", "m"),
+        ("н", "n"),
+        ("њ", "nj"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("ќ", "ḱ"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "h"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("џ", "dž"),
+        ("ш", "š"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// Cyrillic Serbian transliteration table.
+/// implementation of the scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// [`scholarly_rules`] separates `дж`, `лј` and `нј` by "·",
+/// otherwise they are read back as `џ`, `љ` and `њ`.
+pub fn scholarly_sr() -> CharsMapping {
+    [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Ђ", "Đ"),
+        ("Е", "E"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Ј", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("Љ", "Lj"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("Њ", "Nj"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("Ћ", "Ć"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "H"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Џ", "Dž"),
+        ("Ш", "Š"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("ђ", "đ"),
+        ("е", "e"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "i"),
+        ("ј", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("љ", "lj"),
+        ("м", "m"),
+        ("н", "n"),
+        ("њ", "nj"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("ћ", "ć"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "h"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("џ", "dž"),
+        ("ш", "š"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
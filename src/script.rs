@@ -0,0 +1,35 @@
+/// Checks that the character is a letter of the Latin alphabet, with or without diacritics.
+pub(crate) fn is_latin(c: char) -> bool {
+    c.is_alphabetic()
+        && matches!(c,
+            'A'..='Z' | 'a'..='z'
+            | '\u{00C0}'..='\u{024F}'
+            | '\u{1E00}'..='\u{1EFF}')
+}
+
+/// Checks that the character belongs to the Cyrillic script.
+pub(crate) fn is_cyrillic(c: char) -> bool {
+    matches!(c,
+        '\u{0400}'..='\u{052F}'
+        | '\u{1C80}'..='\u{1C8F}'
+        | '\u{2DE0}'..='\u{2DFF}'
+        | '\u{A640}'..='\u{A69F}'
+        | '\u{1E030}'..='\u{1E08F}')
+}
+
+/// Checks that the character is a letter of the script being transliterated:
+/// a Cyrillic character, or a Latin letter in the transliteration from the Latin alphabet.
+pub(crate) fn is_source_letter(c: char, invert: bool) -> bool {
+    if invert {
+        is_latin(c)
+    } else {
+        is_cyrillic(c)
+    }
+}
+
+/// Checks that the character must have a rule: every character outside ASCII
+/// and the Latin letters in the transliteration from the Latin alphabet.
+/// ASCII digits, spaces and punctuation are copied as they are.
+pub(crate) fn needs_rule(c: char, invert: bool) -> bool {
+    !c.is_ascii() || invert && is_latin(c)
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use super::CharsMapping;
+use crate::script::is_latin;
+use crate::transliterator::{words, Transliterator};
+
+/// Words where two letters are written as a Latin digraph, like `дж` in "nadživeti",
+/// which is `надживети` and not `наџивети`. The stems are matched inside words.
+const DIGRAPH_SPLITS: &[(&str, &str)] = &[
+    ("наджив", "nadživ"),
+    ("наджњ", "nadžnj"),
+    ("поджанр", "podžanr"),
+    ("поджел", "podžel"),
+    ("поджуп", "podžup"),
+    ("инјекц", "injekc"),
+    ("инјект", "injekt"),
+    ("конјуг", "konjug"),
+    ("конјункт", "konjunkt"),
+    ("конјункц", "konjunkc"),
+    ("ванјез", "vanjez"),
+    ("панјелин", "panjelin"),
+    ("танјуг", "tanjug"),
+];
+
+/// The letters of Gaj's Latin alphabet, the digraphs are made of them.
+const GAJ_LETTERS: &str = "abcčćdđefghijklmnoprsštuvzž";
+
+/// Serbian Cyrillic and Gaj's Latin alphabet, the official scripts of Serbian.
+/// more details:
+/// [Serbian Cyrillic alphabet](https://en.wikipedia.org/wiki/Serbian_Cyrillic_alphabet#Table)
+///
+/// The letters match one to one, `љ`, `њ` and `џ` are written as the digraphs `lj`, `nj`
+/// and `dž`. The table includes the words where the digraph stands for two letters.
+pub fn gaj_latin() -> CharsMapping {
+    let mut table: CharsMapping = [
+        ("А", "A"),
+        ("Б", "B"),
+        ("В", "V"),
+        ("Г", "G"),
+        ("Д", "D"),
+        ("Ђ", "Đ"),
+        ("Е", "E"),
+        ("Ж", "Ž"),
+        ("З", "Z"),
+        ("И", "I"),
+        ("Ј", "J"),
+        ("К", "K"),
+        ("Л", "L"),
+        ("Љ", "Lj"),
+        ("М", "M"),
+        ("Н", "N"),
+        ("Њ", "Nj"),
+        ("О", "O"),
+        ("П", "P"),
+        ("Р", "R"),
+        ("С", "S"),
+        ("Т", "T"),
+        ("Ћ", "Ć"),
+        ("У", "U"),
+        ("Ф", "F"),
+        ("Х", "H"),
+        ("Ц", "C"),
+        ("Ч", "Č"),
+        ("Џ", "Dž"),
+        ("Ш", "Š"),
+        ("а", "a"),
+        ("б", "b"),
+        ("в", "v"),
+        ("г", "g"),
+        ("д", "d"),
+        ("ђ", "đ"),
+        ("е", "e"),
+        ("ж", "ž"),
+        ("з", "z"),
+        ("и", "i"),
+        ("ј", "j"),
+        ("к", "k"),
+        ("л", "l"),
+        ("љ", "lj"),
+        ("м", "m"),
+        ("н", "n"),
+        ("њ", "nj"),
+        ("о", "o"),
+        ("п", "p"),
+        ("р", "r"),
+        ("с", "s"),
+        ("т", "t"),
+        ("ћ", "ć"),
+        ("у", "u"),
+        ("ф", "f"),
+        ("х", "h"),
+        ("ц", "c"),
+        ("ч", "č"),
+        ("џ", "dž"),
+        ("ш", "š"),
+    ]
+    .iter()
+    .cloned()
+    .collect();
+    table.extend_from_slice(DIGRAPH_SPLITS);
+    table
+}
+
+/// A word with a Latin letter which is not in Gaj's alphabet, like "w" in "Windows",
+/// is a foreign word.
+fn is_foreign(word: &str) -> bool {
+    word.chars()
+        .any(|c| is_latin(c) && c.to_lowercase().any(|lower| !GAJ_LETTERS.contains(lower)))
+}
+
+/// Transliterates Gaj's Latin text word by word, foreign words are kept
+/// as in the transliteration in the Latin alphabet: the policy for unmapped
+/// characters applies only to their characters outside ASCII.
+pub(crate) fn from_gaj_latin<W: fmt::Write + ?Sized>(
+    translit: &Transliterator,
+    src: &str,
+    output: &mut W,
+) -> fmt::Result {
+    for (in_word, part) in words(src) {
+        if in_word && is_foreign(part) {
+            for c in part.chars() {
+                translit.write_char(c, false, output)?;
+            }
+        } else {
+            translit.convert_fmt(part, true, output)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str;
+
+use crate::casing::is_mark;
+use crate::Transliterator;
+
+/// The size of the buffer for reading the input.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+impl Transliterator {
+    /// Transliterates UTF-8 text from `reader` to `writer`.
+    ///
+    /// The input is read in chunks, so the memory does not depend on its size
+    /// (except for long runs of marks like "`", which are kept until the next letter).
+    /// Keys and UTF-8 sequences split between chunks are handled as if the input
+    /// were converted at once by [`convert`](Transliterator::convert).
+    ///
+    /// # Errors
+    ///
+    /// Returns errors of the reader and the writer.
+    /// If the input is not valid UTF-8 an error of the kind `InvalidData` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{gost779b_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(gost779b_ru());
+    /// let mut output = Vec::new();
+    /// trasliterator
+    ///     .convert_stream("Щука".as_bytes(), &mut output, false)
+    ///     .unwrap();
+    /// assert_eq!(b"Shhuka", output.as_slice());
+    ///
+    /// ```
+    pub fn convert_stream<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        invert: bool,
+    ) -> io::Result<()> {
+        // a key and two more characters after it are needed to choose the rule,
+        // and the first character after the key which is not a mark for its case
+        let max_key_len = self.max_key_len(invert);
+        let ready = |rest: &str| {
+            let mut after = rest.chars().skip(max_key_len);
+            after.clone().nth(1).is_some() && after.any(|c| !is_mark(&c))
+        };
+        let mut chunk = vec![0; CHUNK_SIZE];
+        let mut bytes = Vec::new();
+        let mut text = String::new();
+        let mut output = String::new();
+        let mut pos = 0;
+
+        loop {
+            let read = match reader.read(&mut chunk) {
+                Ok(read) => read,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            let eof = read == 0;
+
+            bytes.extend_from_slice(&chunk[..read]);
+            let valid = match str::from_utf8(&bytes) {
+                Ok(valid) => valid.len(),
+                Err(err) if err.error_len().is_none() && !eof => err.valid_up_to(),
+                Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+            };
+            text.push_str(str::from_utf8(&bytes[..valid]).unwrap_or_default());
+            bytes.drain(..valid);
+
+            while pos < text.len() && (eof || ready(&text[pos..])) {
+                pos += self
+                    .write_step(&text, pos, invert, &mut output)
+                    .map_err(io::Error::other)?;
+            }
+
+            writer.write_all(output.as_bytes())?;
+            output.clear();
+
+            if eof {
+                return writer.flush();
+            }
+
+            // keep two characters before the position for the context of the next rule
+            // and, behind marks, the last character which is not a mark for its case
+            let keep: usize = text[..pos].chars().rev().take(2).map(char::len_utf8).sum();
+            let letter = if text[pos - keep..pos].chars().all(|c| is_mark(&c)) {
+                text[..pos - keep].chars().rev().find(|c| !is_mark(c))
+            } else {
+                None
+            };
+            text.drain(..pos - keep);
+            pos = keep;
+            if let Some(letter) = letter {
+                text.insert(0, letter);
+                pos += letter.len_utf8();
+            }
+        }
+    }
+}
+
+/// Converts the input with `convert` in chunks which end with whitespace,
+/// for the conversions which look at whole words. A chunk without whitespace
+/// is kept until the next whitespace or the end of the input.
+pub(crate) fn convert_words<F>(
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+    convert: F,
+) -> io::Result<()>
+where
+    F: Fn(&str) -> String,
+{
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let mut bytes = Vec::new();
+    let mut text = String::new();
+
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(read) => read,
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let eof = read == 0;
+
+        bytes.extend_from_slice(&chunk[..read]);
+        let valid = match str::from_utf8(&bytes) {
+            Ok(valid) => valid.len(),
+            Err(err) if err.error_len().is_none() && !eof => err.valid_up_to(),
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+        text.push_str(str::from_utf8(&bytes[..valid]).unwrap_or_default());
+        bytes.drain(..valid);
+
+        let end = if eof {
+            text.len()
+        } else {
+            text.rfind(char::is_whitespace).map_or(0, |pos| {
+                pos + text[pos..].chars().next().map_or(0, char::len_utf8)
+            })
+        };
+        writer.write_all(convert(&text[..end]).as_bytes())?;
+        text.drain(..end);
+
+        if eof {
+            return writer.flush();
+        }
+    }
+}
+
+/// Converts the input line by line with `convert`.
+pub(crate) fn convert_lines<F>(
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+    convert: F,
+) -> io::Result<()>
+where
+    F: Fn(&str) -> String,
+{
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        writer.write_all(convert(&line).as_bytes())?;
+        line.clear();
+    }
+
+    writer.flush()
+}
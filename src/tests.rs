@@ -1,7 +1,18 @@
-use crate::OrderN995;
+#![allow(clippy::redundant_static_lifetimes)]
+
+use crate::{iternational_passport_2013_ru, OrderN995};
+
+use proptest::prelude::*;
+use std::borrow::Cow;
+use std::io::{self, Read};
 
 use super::{
-    BulgarianOfficial, FromLatin, Gost779B, Language, MacedonianOfficial, Passport2013, ToLatin,
+    analyze, detect_standard, gost779b, match_name, scholarly_rules, AlaLc, Ambiguity, BgnPcgn,
+    BuildError, BulgarianOfficial, Candidate, CharsMapping, Detection, Direction, FromLatin,
+    Gost779A, Gost779B, Kazakh2021, Language, LetterModel, MacedonianOfficial, Mismatch,
+    MongolianMns5217, Passport2013, Scholarly, Segment, SerbianOfficial, Standard, ToLatin,
+    Transliterator, TransliteratorBuilder, UkrainianNational2010, UnmappedPolicy, UzbekOfficial,
+    Variation, VariationKind,
 };
 
 // Russian
@@ -76,8 +87,8 @@ fn test_fn_to_latin_iternational_passport_2013_ru_2() {
 const SOURCE_BY: &'static str = "У рудога вераб'я ў сховішчы \
                                  пад фатэлем ляжаць нейкія гаючыя зёлкі.";
 
-const TRANSLIT_BY: &'static str = "U rudoha verab'ya u` sxovishchy` \
-                                   pad fate`lem lyazhac` nejkiya hayuchy`ya zyolki.";
+const TRANSLIT_BY: &'static str = "U rudoga verab'ya u` sxovishchy` \
+                                   pad fate`lem lyazhacz` nejkiya gayuchy`ya zyolki.";
 
 #[test]
 fn test_fn_to_latin_gost779b_by_1() {
@@ -115,7 +126,7 @@ fn test_fn_from_latin_gost779b_ua_1() {
 #[test]
 fn check_fio_driver_license_01() {
     let test = "КОЗЛОВСКИЙ ДАНИЛА ВАЛЕРЬЕВИЧ";
-    let expected = "KOZLOVSKII DANILA VALEREVICh";
+    let expected = "KOZLOVSKII DANILA VALEREVICH";
 
     assert_eq!(OrderN995::new().to_latin(test), expected);
 }
@@ -194,3 +205,1393 @@ const TRANSLIT_MK: &'static str =
 fn test_macedonian_to_latin() {
     assert_eq!(MacedonianOfficial::new().to_latin(SOURCE_MK), TRANSLIT_MK);
 }
+
+#[test]
+fn test_transliterator_does_not_rewrite_output() {
+    let table: CharsMapping = [("а", "b"), ("b", "c")].to_vec();
+
+    assert_eq!(Transliterator::new(table).to_latin("аb"), "bc");
+}
+
+#[test]
+fn test_transliterator_longest_match() {
+    let table: CharsMapping = [("с", "s"), ("ш", "sh"), ("щ", "shh"), ("х", "h")].to_vec();
+    let trn = Transliterator::new(table);
+
+    assert_eq!(trn.from_latin("shhshsh"), "щшш");
+    assert_eq!(trn.to_latin("сшщх"), "sshshhh");
+}
+
+#[test]
+fn test_upper_case_digraphs() {
+    assert_eq!(
+        Passport2013::new().to_latin("ЩУКИН Щукин"),
+        "SHCHUKIN Shchukin"
+    );
+    assert_eq!(
+        Gost779B::new(Language::Ru).to_latin("ЖУК ЧАЩА"),
+        "ZHUK CHASHHA"
+    );
+    assert_eq!(BulgarianOfficial::new().to_latin("ЮГ Юг"), "YUG Yug");
+    assert_eq!(MacedonianOfficial::new().to_latin("ЉУБОВ"), "LJUBOV");
+}
+
+#[test]
+fn test_upper_case_digraphs_from_latin() {
+    let trn = Gost779B::new(Language::Ru);
+
+    assert_eq!(trn.from_latin("CHASHHA Chashha"), "ЧАЩА Чаща");
+}
+
+#[test]
+fn test_gost779b_letter_tse() {
+    let trn = Gost779B::new(Language::Ru);
+
+    assert_eq!(
+        trn.to_latin("Царь цирк Цюрих улица"),
+        "Czar` cirk Cyurix ulicza"
+    );
+    assert_eq!(trn.to_latin("ЦАРИЦА ЦЕНТР"), "CZARICZA CENTR");
+    assert_eq!(trn.from_latin("Czar` cirk ulicza"), "Царь цирк улица");
+}
+
+#[test]
+fn test_gost779b_word_final_letters() {
+    let trn = Gost779B::new(Language::Ru);
+
+    // GOST 7.79 System B has no rules for the end of a word
+    assert_eq!(
+        trn.to_latin("мы красный они синий ры ри"),
+        "my` krasny`j oni sinij ry` ri"
+    );
+    assert_eq!(
+        trn.from_latin("my` krasny`j oni sinij"),
+        "мы красный они синий"
+    );
+    assert_eq!(trn.to_latin("і ѣ"), "і ѣ");
+}
+
+#[test]
+fn test_ukrainian_national_2010() {
+    let test = "Алушта Борщагівка Вінниця Гадяч Згорани Ґалаґан Єнакієве Наєнко Закарпаття \
+                Їжакевич Кадиївка Йосипівка Стрий Олексій Київ Миколаїв Ужгород Біла Церква \
+                Гоща Русь Юрій Крюківка Яготин Ічня Розгон Знам'янка";
+    let expected = "Alushta Borshchahivka Vinnytsia Hadiach Zghorany Galagan Yenakiieve Naienko Zakarpattia \
+                    Yizhakevych Kadyivka Yosypivka Stryi Oleksii Kyiv Mykolaiv Uzhhorod Bila Tserkva \
+                    Hoshcha Rus Yurii Kriukivka Yahotyn Ichnia Rozghon Znamianka";
+
+    assert_eq!(UkrainianNational2010::new().to_latin(test), expected);
+}
+
+#[test]
+fn test_ukrainian_national_2010_quotes() {
+    let trasliterator = UkrainianNational2010::new();
+
+    assert_eq!(trasliterator.to_latin("'Юрій'"), "'Yurii'");
+    assert_eq!(trasliterator.to_latin("‘Яготин’"), "‘Yahotyn’");
+    assert_eq!(
+        trasliterator.to_latin("’Єнакієве’, Знам’янка"),
+        "’Yenakiieve’, Znamianka"
+    );
+    assert_eq!(
+        trasliterator.to_latin("rock'n'roll, rock’n’roll у Мар'їнці"),
+        "rock'n'roll, rock’n’roll u Marintsi"
+    );
+}
+
+#[test]
+fn test_ukrainian_national_2010_upper() {
+    assert_eq!(
+        UkrainianNational2010::new().to_latin("ЄНАКІЄВЕ РОЗГОН"),
+        "YENAKIIEVE ROZGHON"
+    );
+}
+
+/// Returns the input one byte at a time to split keys and UTF-8 sequences.
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.split_first() {
+            Some((&first, rest)) if !buf.is_empty() => {
+                buf[0] = first;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn test_stream_gost779b_ru() {
+    let trn = Gost779B::new(Language::Ru);
+    let mut output = Vec::new();
+
+    trn.to_latin_stream(&mut ByteReader(SOURCE_RU.as_bytes()), &mut output)
+        .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), TRANSLIT_GOST779B_RU);
+
+    let mut output = Vec::new();
+    trn.from_latin_stream(
+        &mut ByteReader(TRANSLIT_GOST779B_RU.as_bytes()),
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), SOURCE_RU);
+}
+
+#[test]
+fn test_stream_runs_of_marks() {
+    let trn = Gost779B::new(Language::By);
+    let marks = "`".repeat(9);
+    for src in [
+        format!("T{} da", marks),
+        format!("t{}A da", marks),
+        format!("U`{} t{}", marks, marks),
+    ] {
+        let mut output = Vec::new();
+        trn.from_latin_stream(&mut ByteReader(src.as_bytes()), &mut output)
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), trn.from_latin(&src));
+    }
+}
+
+#[test]
+fn test_stream_words() {
+    let trn = SerbianOfficial::new();
+    let src = "Nadživeti Windows, Džep i Njiva.\nInjekcija";
+    let mut output = Vec::new();
+    trn.from_latin_stream(&mut ByteReader(src.as_bytes()), &mut output)
+        .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), trn.from_latin(src));
+
+    let trn = UzbekOfficial::new();
+    let src = "O'zbekiston, Isʼhoq, Yevropa ekran SHEʼR";
+    let mut output = Vec::new();
+    trn.from_latin_stream(&mut ByteReader(src.as_bytes()), &mut output)
+        .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), trn.from_latin(src));
+}
+
+#[test]
+fn test_stream_invalid_utf8() {
+    let mut output = Vec::new();
+    let err = Passport2013::new()
+        .to_latin_stream(&mut &b"\xd0\x90\xff"[..], &mut output)
+        .unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_to_latin_into_reuses_buffer() {
+    let trn = OrderN995::new();
+    let mut buffer = String::new();
+
+    trn.to_latin_into("Иванов ", &mut buffer);
+    trn.to_latin_into("Иван", &mut buffer);
+    assert_eq!(buffer, "Ivanov Ivan");
+
+    let mut output = String::new();
+    Gost779B::new(Language::Ua)
+        .from_latin_fmt(TRANSLIT_UA, &mut output)
+        .unwrap();
+    assert_eq!(output, SOURCE_UA);
+}
+
+#[test]
+fn test_to_latin_cow() {
+    let trn = Passport2013::new();
+
+    assert!(matches!(
+        trn.to_latin_cow("ivanov@example.com"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(trn.to_latin_cow("Müller"), Cow::Borrowed(_)));
+    assert_eq!(trn.to_latin_cow("ID 42: Щукин"), "ID 42: Shchukin");
+
+    let trn = Gost779B::new(Language::Ru);
+    assert!(matches!(trn.from_latin_cow("12-34"), Cow::Borrowed(_)));
+    assert_eq!(trn.from_latin_cow("12 shhuka"), "12 щука");
+}
+
+#[test]
+fn test_to_latin_strict() {
+    let err = Passport2013::new()
+        .to_latin_strict("Їжак і Ґанок")
+        .unwrap_err();
+    assert_eq!(err.unmapped(), &[(0, 'Ї'), (9, 'і'), (12, 'Ґ')]);
+    assert_eq!(
+        err.to_string(),
+        "untransliterated characters: 'Ї' at byte 0, 'і' at byte 9, 'Ґ' at byte 12"
+    );
+
+    assert_eq!(
+        Passport2013::new().to_latin_strict("Щукин, ID 42").unwrap(),
+        "Shchukin, ID 42"
+    );
+    assert_eq!(
+        Passport2013::new()
+            .to_latin_strict("Москва — столица © 2020")
+            .unwrap(),
+        "Moskva — stolitsa © 2020"
+    );
+    let err = Passport2013::new().to_latin_strict("Ѣ «ѳ»").unwrap_err();
+    assert_eq!(err.unmapped(), &[(0, 'Ѣ'), (5, 'ѳ')]);
+}
+
+#[test]
+fn test_from_latin_strict() {
+    let err = Gost779B::new(Language::Ru)
+        .from_latin_strict("Moskva, Hamburg")
+        .unwrap_err();
+
+    assert_eq!(err.unmapped(), &[(8, 'H')]);
+}
+
+#[test]
+fn test_unmapped_policy() {
+    let src = "Їжак, 2024";
+
+    let trn = Passport2013::new().with_unmapped(UnmappedPolicy::Replace("?".to_owned()));
+    assert_eq!(trn.to_latin(src), "?zhak, 2024");
+
+    let trn = Passport2013::new().with_unmapped(UnmappedPolicy::custom(|c| {
+        c.to_uppercase()
+            .map(|c| if c == 'Ї' { 'I' } else { c })
+            .collect()
+    }));
+    assert_eq!(trn.to_latin(src), "Izhak, 2024");
+
+    let trn = Gost779B::new(Language::Ru).with_unmapped(UnmappedPolicy::Remove);
+    assert_eq!(trn.from_latin("Hamburg"), "амбург");
+    assert!(matches!(trn.from_latin_cow("42"), Cow::Borrowed(_)));
+    assert_eq!(trn.from_latin_cow("Hh"), "");
+}
+
+#[test]
+fn test_unmapped_policy_ascii_output() {
+    let src = "Ёлка — ёж «Київ» α é";
+
+    let trn = Passport2013::new().with_unmapped(UnmappedPolicy::Remove);
+    let res = trn.to_latin(src);
+    assert_eq!(res, "Elka  ezh Kiv  ");
+    assert!(res.is_ascii());
+
+    let trn = Passport2013::new().with_unmapped(UnmappedPolicy::Escape);
+    let res = trn.to_latin(src);
+    assert_eq!(
+        res,
+        "Elka \\u{2014} ezh \\u{ab}Ki\\u{457}v\\u{bb} \\u{3b1} \\u{e9}"
+    );
+    assert!(res.is_ascii());
+    assert!(trn.to_latin_cow("α").is_ascii());
+
+    // the strict transliteration reports only Cyrillic characters
+    assert_eq!(Passport2013::new().to_latin_strict("α β").unwrap(), "α β");
+}
+
+#[test]
+fn test_builder_overrides_scheme() {
+    let trn = TransliteratorBuilder::from(Passport2013::new())
+        .insert("Х", "H")
+        .insert("х", "h")
+        .remove("№")
+        .build()
+        .unwrap();
+
+    assert_eq!(trn.to_latin("Харитон Михайлов №1"), "Hariton Mihailov №1");
+}
+
+#[test]
+fn test_builder_case_aware() {
+    let trn = TransliteratorBuilder::from_table(iternational_passport_2013_ru())
+        .case_aware(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(trn.to_latin("ЩУКИН"), "ShchUKIN");
+}
+
+#[test]
+fn test_builder_validation() {
+    let err = TransliteratorBuilder::from(Passport2013::new())
+        .direction(Direction::Both)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::AmbiguousTranslit {
+            translit: "E",
+            first: "Е",
+            second: "Ё",
+        }
+    );
+
+    let err = TransliteratorBuilder::from_table(vec![("а", "a"), ("а", "b")])
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::UnreachableRule {
+            source: "а",
+            translit: "b",
+        }
+    );
+
+    assert!(TransliteratorBuilder::from(Gost779B::new(Language::Ua))
+        .direction(Direction::Both)
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn test_analyze_gost779b_ru() {
+    let analysis = Gost779B::new(Language::Ru).as_ref().analyze();
+
+    assert!(analysis.many_to_one.is_empty());
+    assert!(analysis.empty.is_empty());
+    assert!(analysis.prefix_conflicts.contains(&("`", "``")));
+    assert!(analysis.prefix_conflicts.contains(&("sh", "shh")));
+    assert!(!analysis.is_reversible());
+}
+
+#[test]
+fn test_analyze_ambiguity() {
+    let table: CharsMapping =
+        [("с", "s"), ("ш", "sh"), ("х", "h"), ("ь", "`"), ("ъ", "``")].to_vec();
+
+    assert_eq!(
+        analyze(&table).ambiguity,
+        Some(Ambiguity {
+            translit: "``".to_owned(),
+            first: vec!["ъ"],
+            second: vec!["ь", "ь"],
+        })
+    );
+    assert!(analyze(&table[..3]).ambiguity.is_some());
+    assert!(analyze(&[("с", "s"), ("ш", "sh"), ("щ", "shh")]).is_reversible());
+}
+
+#[test]
+fn test_builder_lossless() {
+    let err = TransliteratorBuilder::from_table([("ь", "`"), ("ъ", "``")].to_vec())
+        .direction(Direction::Lossless)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "\"``\" can be read as both \"ъ\" and \"ьь\""
+    );
+}
+
+#[test]
+fn test_gost779b_capital_signs() {
+    let trasliterator = Gost779B::new(Language::Ru);
+
+    assert_eq!(trasliterator.to_latin("ОБЪЁМ МЕЛЬ"), "OB``YOM MEL`");
+    assert_eq!(trasliterator.from_latin("OB``YOM MEL`"), "ОБЪЁМ МЕЛЬ");
+    assert_eq!(trasliterator.from_latin("Ob``yom mel`"), "Объём мель");
+    assert!(Gost779B::new(Language::Ua)
+        .verify_round_trip("ДЕНЬ І НІЧ")
+        .is_ok());
+}
+
+#[test]
+fn test_verify_round_trip() {
+    let trasliterator = Gost779B::new(Language::Ru);
+
+    assert!(trasliterator.verify_round_trip(SOURCE_RU).is_ok());
+    assert!(Gost779B::new(Language::By)
+        .verify_round_trip(SOURCE_BY)
+        .is_ok());
+    assert!(Gost779B::new(Language::Ua)
+        .verify_round_trip(SOURCE_UA)
+        .is_ok());
+
+    // "ьь" and "ъ" have the same transliteration
+    let err = trasliterator.verify_round_trip("уьь").unwrap_err();
+    assert_eq!(err.offset(), 2);
+    assert_eq!(err.translit(), "u``");
+    assert_eq!(err.restored(), "уъ");
+    assert_eq!(
+        err.to_string(),
+        "\"u``\" is restored as \"уъ\", the first difference at byte 2"
+    );
+}
+
+#[test]
+fn test_gost779b_lossy_sequences() {
+    let cases = [
+        (Language::Ru, "ЬЪ", "```", "ъь"),
+        (Language::Ru, "мель", "mel`", "мель"),
+        (Language::Ru, "Ь", "`", "ь"),
+        (Language::Ru, "Аь", "A`", "АЬ"),
+        (Language::Ru, "нЬ", "n`", "нь"),
+        (Language::Ru, "подЪезд", "pod``ezd", "подъезд"),
+        (Language::Ru, "ОБЪЁМ", "OB``YO`M", "ОБЪЁМ"),
+        (Language::Ru, "ьь", "``", "ъ"),
+        (Language::Ru, "еъ", "e``", "эь"),
+        (Language::By, "еь", "e`", "э"),
+        (Language::By, "уь", "u`", "ў"),
+        (Language::By, "згода", "zgoda", "згода"),
+        (Language::Ua, "гь", "g`", "ґ"),
+    ];
+
+    for &(lang, src, translit, restored) in cases.iter() {
+        let res = Gost779B::new(lang).verify_round_trip(src);
+        if src == restored {
+            assert_eq!(res, Ok(()));
+        } else {
+            let err = res.unwrap_err();
+            assert_eq!((err.translit(), err.restored()), (translit, restored));
+        }
+    }
+}
+
+fn texts(candidates: &[Candidate]) -> Vec<&str> {
+    candidates
+        .iter()
+        .map(|candidate| candidate.text.as_str())
+        .collect()
+}
+
+#[test]
+fn test_from_latin_candidates_passport_2013() {
+    let trasliterator = Passport2013::new();
+
+    let elka = trasliterator.from_latin_candidates("Elka", 3);
+    assert_eq!(texts(&elka)[0], "Ёлка");
+    assert!(texts(&elka).contains(&"Елка"));
+    assert!(elka.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+    let aleksei = trasliterator.from_latin_candidates("Aleksei", 4);
+    assert_eq!(texts(&aleksei)[0], "Алексей");
+    assert!(texts(&aleksei).contains(&"Алексеи"));
+
+    let name = trasliterator.from_latin_candidates("OLGA IVANOVA", 2);
+    assert!(texts(&name).contains(&"ОЛЬГА ИВАНОВА"));
+
+    // a sign follows a consonant
+    for candidate in trasliterator.from_latin_candidates("Olga Aleksei", 20) {
+        let chars: Vec<char> = candidate.text.to_lowercase().chars().collect();
+        assert!(!"ъь".contains(chars[0]), "{}", candidate.text);
+        assert!(
+            chars
+                .windows(2)
+                .all(|pair| !"ъь".contains(pair[1]) || !"аеёиоуыэюяй ".contains(pair[0])),
+            "{}",
+            candidate.text
+        );
+    }
+
+    for candidate in trasliterator.from_latin_candidates("Shchukin Iurii", 10) {
+        assert_eq!(trasliterator.to_latin(&candidate.text), "Shchukin Iurii");
+    }
+}
+
+#[test]
+fn test_from_latin_candidates_other_schemes() {
+    let candidates = UkrainianNational2010::new().from_latin_candidates("Yevhen", 1);
+    assert_eq!(texts(&candidates), ["Євген"]);
+
+    let candidates = BulgarianOfficial::new().from_latin_candidates("Hristo", 1);
+    assert_eq!(texts(&candidates), ["Христо"]);
+
+    let candidates = MacedonianOfficial::new().from_latin_candidates("Gjorgji", 1);
+    assert_eq!(texts(&candidates), ["Ѓорѓи"]);
+
+    assert!(Passport2013::new().from_latin_candidates("", 3)[0]
+        .text
+        .is_empty());
+    assert!(Passport2013::new()
+        .from_latin_candidates("Elka", 0)
+        .is_empty());
+}
+
+#[test]
+fn test_candidates_custom_model() {
+    let table: CharsMapping = [("е", "e"), ("э", "e"), ("х", "h")].to_vec();
+    let trasliterator = Transliterator::new(table);
+
+    let model = LetterModel::from_text("эхэ");
+    assert_eq!(
+        texts(&trasliterator.candidates("ehe", &model, 4)),
+        ["эхэ", "эхе", "ехэ", "ехе"]
+    );
+}
+
+#[test]
+fn test_detect_standard_with_original() {
+    let detections = detect_standard("Shhukin", Some("Щукин"));
+
+    assert_eq!(detections[0].standard, Standard::Gost779B(Language::Ru));
+    assert!(detections[0].is_exact());
+    assert_eq!(detections[0].fit, 1.0);
+
+    let passport = detections
+        .iter()
+        .find(|detection| detection.standard == Standard::Passport2013)
+        .unwrap();
+    assert_eq!(
+        passport.mismatches,
+        [Mismatch {
+            offset: 2,
+            found: String::new(),
+            expected: Some("c".to_owned()),
+        }]
+    );
+    assert!(passport.fit < 1.0);
+}
+
+#[test]
+fn test_detect_standard_without_original() {
+    let detections = detect_standard("Ob``yom Xrushhyov", None);
+    assert_eq!(detections[0].standard, Standard::Gost779B(Language::Ru));
+    assert!(detections[0].is_exact());
+
+    let detections = detect_standard("Hristo Stoichkov", None);
+    let passport = detections
+        .iter()
+        .find(|detection| detection.standard == Standard::Passport2013)
+        .unwrap();
+    assert_eq!(
+        passport.mismatches,
+        [Mismatch {
+            offset: 0,
+            found: "H".to_owned(),
+            expected: None,
+        }]
+    );
+    assert!(detections
+        .iter()
+        .take_while(|detection| detection.is_exact())
+        .any(|detection| detection.standard == Standard::BulgarianOfficial));
+
+    assert_eq!(detect_standard("", None).len(), Standard::ALL.len());
+}
+
+#[test]
+fn test_detect_standard_ranking() {
+    let position = |detections: &[Detection], standard: Standard| {
+        detections
+            .iter()
+            .position(|detection| detection.standard == standard)
+            .unwrap()
+    };
+
+    let detections = detect_standard("Moskva", None);
+    assert!(detections.iter().all(Detection::is_exact));
+    assert!(
+        position(&detections, Standard::Passport2013) < position(&detections, Standard::Gost779A)
+    );
+    assert!(
+        position(&detections, Standard::Passport2013)
+            < position(&detections, Standard::Scholarly(Language::Ru))
+    );
+    assert!(detections[0]
+        .standard
+        .transliterator()
+        .unwrap()
+        .rules()
+        .iter()
+        .all(|rule| rule.translit.is_ascii()));
+
+    let detections = detect_standard("Moskva", Some("Москва"));
+    assert!(
+        position(&detections, Standard::Passport2013) < position(&detections, Standard::Gost779A)
+    );
+
+    let detections = detect_standard("Moskva Ščukin", None);
+    assert_eq!(detections[0].standard, Standard::Scholarly(Language::Ru));
+
+    for (latin, original) in [("Shchukin", "Щукин"), ("Iurii Tsvetaev", "Юрий Цветаев")]
+    {
+        let detections = detect_standard(latin, None);
+        assert!(detections[0].is_exact());
+        assert_eq!(
+            latin,
+            detections[0]
+                .standard
+                .transliterator()
+                .unwrap()
+                .convert(original, false)
+        );
+        assert!(
+            position(&detections, Standard::Passport2013)
+                < position(&detections, Standard::Gost779B(Language::Ru))
+        );
+    }
+}
+
+#[test]
+fn test_detect_standard_alignment() {
+    let detections = detect_standard("Chrushchev Sergey", Some("Хрущёв Сергей"));
+    let passport = detections
+        .iter()
+        .find(|detection| detection.standard == Standard::Passport2013)
+        .unwrap();
+    assert_eq!(
+        passport.mismatches,
+        [
+            Mismatch {
+                offset: 0,
+                found: "C".to_owned(),
+                expected: Some("K".to_owned()),
+            },
+            Mismatch {
+                offset: 16,
+                found: "y".to_owned(),
+                expected: Some("i".to_owned()),
+            },
+        ]
+    );
+    assert_eq!(passport.fit, 1.0 - 2.0 / 17.0);
+
+    let detections = detect_standard("KhrushchevSergei", Some("Хрущёв Сергей"));
+    assert_eq!(detections[0].standard, Standard::Passport2013);
+    assert_eq!(
+        detections[0].mismatches,
+        [Mismatch {
+            offset: 10,
+            found: String::new(),
+            expected: Some(" ".to_owned()),
+        }]
+    );
+
+    let long = "Moskva ".repeat(1000);
+    let detections = detect_standard(&long, Some(&"Москва ".repeat(1000)));
+    assert!(detections[0].is_exact());
+}
+
+#[test]
+fn test_match_name_exact() {
+    let result = match_name("Ольга Ильина", "OLGA ILINA");
+    assert_eq!(result.standard, Standard::Passport2013);
+    assert_eq!(result.confidence, 1.0);
+    assert!(result.variations.is_empty());
+
+    let result = match_name("Юлия Хрущёва", "Yuliya Xrushhyova");
+    assert_eq!(result.standard, Standard::Gost779B(Language::Ru));
+    assert_eq!(result.confidence, 1.0);
+
+    assert_eq!(match_name("Подъячев", "Podieiachev").confidence, 1.0);
+}
+
+#[test]
+fn test_match_name_variations() {
+    for latin in &["Khrushchev", "Hrushchev", "Xrushchev"] {
+        assert!(match_name("Хрущев", latin).is_match(), "{}", latin);
+    }
+
+    let result = match_name("Юрий Гоголь", "Yuriy Gogol");
+    assert!(result.is_match());
+    assert_eq!(result.standard, Standard::Passport2013);
+    assert_eq!(
+        result.variations,
+        [
+            Variation {
+                offset: 0,
+                source: "Ю".to_owned(),
+                translit: "Yu".to_owned(),
+                kind: VariationKind::Standard(Standard::Gost779B(Language::Ru)),
+            },
+            Variation {
+                offset: 3,
+                source: "ий".to_owned(),
+                translit: "iy".to_owned(),
+                kind: VariationKind::Common,
+            },
+        ]
+    );
+    assert!(result.confidence < 1.0 && result.confidence > 0.8);
+
+    let result = match_name("Юрий", "Yuri");
+    assert!(result.is_match());
+    assert_eq!(result.standard, Standard::Gost779B(Language::Ru));
+    assert_eq!(
+        result.variations,
+        [Variation {
+            offset: 3,
+            source: "ий".to_owned(),
+            translit: "i".to_owned(),
+            kind: VariationKind::Common,
+        }]
+    );
+    assert_eq!(result.confidence, 0.9);
+
+    let result = match_name("Подъячев", "Podiachev");
+    assert_eq!(result.variations[0].kind, VariationKind::Omitted);
+    assert_eq!(
+        result.to_string(),
+        "Passport 2013, confidence 0.95: \"ъ\" as \"\" (omitted)"
+    );
+}
+
+#[test]
+fn test_match_name_mismatch() {
+    let result = match_name("Иванов", "Petrov");
+    assert!(!result.is_match());
+    assert!(result.confidence < 0.1);
+
+    let result = match_name("Сидоров", "Sidorof");
+    assert!(!result.is_match());
+    assert_eq!(result.variations[0].offset, 6);
+    assert_eq!(result.variations[0].kind, VariationKind::Mismatch);
+}
+
+#[test]
+fn test_to_latin_with_alignment() {
+    let alignment = Passport2013::new().to_latin_with_alignment("Ольга!");
+
+    assert_eq!(alignment.output, "Olga!");
+    assert_eq!(
+        alignment.segments,
+        [
+            Segment {
+                source: 0..2,
+                output: 0..1,
+            },
+            Segment {
+                source: 2..4,
+                output: 1..2,
+            },
+            Segment {
+                source: 4..6,
+                output: 2..2,
+            },
+            Segment {
+                source: 6..8,
+                output: 2..3,
+            },
+            Segment {
+                source: 8..10,
+                output: 3..4,
+            },
+            Segment {
+                source: 10..11,
+                output: 4..5,
+            },
+        ]
+    );
+
+    assert_eq!(alignment.source_range(0..4), 0..10);
+    assert_eq!(alignment.source_range(2..3), 6..8);
+    assert_eq!(alignment.source_range(3..3), 8..8);
+    assert_eq!(alignment.source_range(5..5), 11..11);
+    assert_eq!(alignment.output_range(4..6), 2..2);
+    assert_eq!(alignment.output_range(2..8), 1..3);
+}
+
+#[test]
+fn test_from_latin_with_alignment() {
+    let alignment = Gost779B::new(Language::Ru).from_latin_with_alignment("Shhuka");
+
+    assert_eq!(alignment.output, "Щука");
+    assert_eq!(alignment.segments[0].source, 0..3);
+    assert_eq!(alignment.segments[0].output, 0..2);
+    assert_eq!(alignment.source_range(2..4), 3..4);
+
+    let alignment = Gost779B::new(Language::Ru).to_latin_with_alignment(SOURCE_RU);
+    assert_eq!(alignment.output, TRANSLIT_GOST779B_RU);
+    let (mut source, mut output) = (0, 0);
+    for segment in &alignment.segments {
+        assert_eq!(
+            (segment.source.start, segment.output.start),
+            (source, output)
+        );
+        source = segment.source.end;
+        output = segment.output.end;
+    }
+    assert_eq!(
+        (source, output),
+        (SOURCE_RU.len(), TRANSLIT_GOST779B_RU.len())
+    );
+}
+
+#[test]
+fn test_gost779a() {
+    let trasliterator = Gost779A::new();
+
+    let src = "Щёлково, Ўладзімір, Ґанок, Ђорђе, Єва, Ѕвезда, Љубља";
+    let res = trasliterator.to_latin(src);
+    assert_eq!("Ŝëlkovo, Ǔladzìmìr, G̀anok, Đorđe, Êva, Ẑvezda, L̂ubl̂a", res);
+    assert_eq!(src, trasliterator.from_latin(&res));
+
+    let src = "ОБЪЁМ подъезда, ЪЕ";
+    let res = trasliterator.to_latin(src);
+    assert_eq!("OBʺËM podʺezda, ʺE", res);
+    assert_eq!(src, trasliterator.from_latin(&res));
+
+    // the signs have no case in the Latin alphabet
+    for (src, restored) in [("Ъем", "ъем"), ("подъЕзд", "подЪЕзд"), ("Ь", "ь")]
+    {
+        let err = trasliterator.verify_round_trip(src).unwrap_err();
+        assert_eq!(err.restored(), restored);
+    }
+}
+
+#[test]
+fn test_gost779a_is_reversible() {
+    let trasliterator = Gost779A::new();
+    let report = trasliterator.as_ref().analyze();
+
+    assert!(report.is_reversible(), "{:?}", report);
+}
+
+#[test]
+fn test_gost779b_languages() {
+    for &lang in Language::ALL {
+        let analysis = analyze(&gost779b(lang));
+        assert!(
+            analysis.many_to_one.is_empty(),
+            "{:?}: {:?}",
+            lang,
+            analysis
+        );
+    }
+
+    let cases = [
+        (Language::Bg, "Щастие, България", "Shtastie, Ba`lgariya"),
+        (
+            Language::Mk,
+            "Ѓорѓија, Ќерка, Ѕвезда",
+            "G`org`ija, K`erka, Z`vezda",
+        ),
+        (Language::Sr, "Ђорђе Петровић", "D`ord`e Petrovic`"),
+        (Language::Kz, "Қазақстан, Өскемен", "K`azak`stan, O`skemen"),
+        (Language::Kg, "Күнөстүү", "Ku`no`stu`u`"),
+        (
+            Language::Tj,
+            "Ҷумҳурии Тоҷикистон",
+            "Ch`umx`urii Toch`ikiston",
+        ),
+        (Language::Uz, "Ўзбекистон, Тошкент", "U`zbekiston, Toshkent"),
+        (Language::Tt, "Җәй", "Zh`a`j"),
+        (Language::Cv, "Чӑваш, Шупашкар", "Cha`vash, Shupashkar"),
+        (
+            Language::Sah,
+            "Саха Өрөспүүбүлүкэтэ",
+            "Saxa O`ro`spu`u`bu`lu`ke`te`",
+        ),
+        (Language::Ba, "Башҡортостан", "Bashk`ortostan"),
+    ];
+    for (lang, src, latin) in cases {
+        let trasliterator = Gost779B::new(lang);
+        assert_eq!(latin, trasliterator.to_latin(src), "{:?}", lang);
+        assert_eq!(src, trasliterator.from_latin(latin), "{:?}", lang);
+    }
+}
+
+#[test]
+fn test_bgn_pcgn_ru() {
+    let trasliterator = BgnPcgn::new(Language::Ru).unwrap();
+
+    let res = trasliterator.to_latin("Елена Ёлкина, Воронеж, Юрьев, Объект, Озёрск, Маяк");
+    assert_eq!(
+        "Yelena Yëlkina, Voronezh, Yur’yev, Ob”yekt, Ozërsk, Mayak",
+        res
+    );
+    assert_eq!("YËLKINO, ZAYEZD", trasliterator.to_latin("ЁЛКИНО, ЗАЕЗД"));
+    assert_eq!("Sovetskaya", trasliterator.to_latin("Советская"));
+
+    let trasliterator = BgnPcgn::with_separator(Language::Ru).unwrap();
+    let res = trasliterator.to_latin("Советская, Сходня, Веснушчатый, Ыапа, Цхинвали");
+    assert_eq!(
+        "Sovet·skaya, S·khodnya, Vesnush·chatyy, Y·apa, Ts·khinvali",
+        res
+    );
+    assert_eq!("SOVET·SKAYA", trasliterator.to_latin("СОВЕТСКАЯ"));
+}
+
+#[test]
+fn test_bgn_pcgn_other_languages() {
+    let trasliterator = BgnPcgn::new(Language::Ua).unwrap();
+    let res = trasliterator.to_latin("Київ, Запоріжжя, Львів, Слов'янськ, Згурівка");
+    assert_eq!("Kyyiv, Zaporizhzhya, L’viv, Slov”yans’k, Zhurivka", res);
+    let trasliterator = BgnPcgn::with_separator(Language::Ua).unwrap();
+    assert_eq!("Z·hurivka", trasliterator.to_latin("Згурівка"));
+
+    let trasliterator = BgnPcgn::new(Language::By).unwrap();
+    let res = trasliterator.to_latin("Мінск, Гомель, Беларусь, Магілёў, Аб'езд");
+    assert_eq!("Minsk, Homel’, Belarus’, Mahilyow, Ab”yezd", res);
+
+    let trasliterator = BgnPcgn::new(Language::Bg).unwrap();
+    assert_eq!(
+        "Sofia, SOFIA, Shumen",
+        trasliterator.to_latin("София, СОФИЯ, Шумен")
+    );
+
+    assert!(BgnPcgn::new(Language::Kz).is_none());
+    assert!(BgnPcgn::with_separator(Language::Mk).is_none());
+    assert_eq!("BGN/PCGN (Ua)", Standard::BgnPcgn(Language::Ua).to_string());
+    assert!(Standard::BgnPcgn(Language::Kz).transliterator().is_none());
+}
+
+#[test]
+fn test_ala_lc() {
+    let trasliterator = AlaLc::new(Language::Ru).unwrap();
+    assert_eq!(
+        "T\u{361}SARʹ, Ėkonomika",
+        trasliterator.to_latin("ЦАРЬ, Экономика")
+    );
+
+    let trasliterator = AlaLc::new(Language::Ua).unwrap();
+    let res = trasliterator.to_latin("Київ, Ґалаґан, Слов'янськ, Єнакієве");
+    assert_eq!(
+        "Kyïv, Galagan, Slovʺi\u{361}ansʹk, I\u{361}enakii\u{361}eve",
+        res
+    );
+
+    let trasliterator = AlaLc::new(Language::By).unwrap();
+    let res = trasliterator.to_latin("Магілёў, Брэст");
+    assert_eq!("Mahili\u{361}oŭ, Brėst", res);
+
+    let trasliterator = AlaLc::ascii(Language::Ua).unwrap();
+    let res = trasliterator.to_latin("Київ, Слов'янськ");
+    assert_eq!("Kyiv, Slov\"ians'k", res);
+    assert!(res.is_ascii());
+
+    assert!(AlaLc::new(Language::Bg).is_none());
+    assert!(AlaLc::ascii(Language::Kz).is_none());
+
+    let detections = detect_standard("Mahili\u{361}oŭ", Some("Магілёў"));
+    assert_eq!(Standard::AlaLc(Language::By), detections[0].standard);
+    assert!(detections[0].is_exact());
+}
+
+#[test]
+fn test_scholarly() {
+    let cases = [
+        (
+            Language::Ru,
+            "Объём, ЩЁЛКОВО, Ѳеодоръ, Міръ, Сѣверъ",
+            "Obʺëm, ŠČËLKOVO, F\u{300}eodorʺ, Mìrʺ, Sěverʺ",
+        ),
+        (
+            Language::Ua,
+            "Київ, Львів, Ґудзь, Євген",
+            "Kyjiv, Lʹviv, Gudzʹ, Jevhen",
+        ),
+        (
+            Language::By,
+            "Магілёў, Брэст, Мінск",
+            "Mahilëŭ, Brèst, Minsk",
+        ),
+        (
+            Language::Bg,
+            "Щастие, България, Ямбол",
+            "Štastie, Bălgarija, Jambol",
+        ),
+        (
+            Language::Mk,
+            "Ѓорѓи, Ќерка, Ѕвезда, Љубов, Џамија",
+            "Ǵorǵi, Ḱerka, Dzvezda, Ljubov, Džamija",
+        ),
+        (
+            Language::Sr,
+            "Ђорђе, Ћуприја, ЉУБАВ, Њива, Џеп",
+            "Đorđe, Ćuprija, LJUBAV, Njiva, Džep",
+        ),
+    ];
+    for (lang, src, latin) in cases {
+        let trasliterator = Scholarly::new(lang).unwrap();
+        let analysis = trasliterator.as_ref().analyze();
+        assert!(
+            analysis.many_to_one.is_empty(),
+            "{:?}: {:?}",
+            lang,
+            analysis
+        );
+        assert_eq!(latin, trasliterator.to_latin(src), "{:?}", lang);
+        assert_eq!(src, trasliterator.from_latin(latin), "{:?}", lang);
+    }
+
+    let cases = [
+        (Language::Ru, "Майами, веснушчатый", "Maj·ami, vesnuš·čatyj"),
+        (Language::Ua, "Йеллоустоун", "J·elloustoun"),
+        (Language::Bg, "каштан, Йордан", "kaš·tan, Jordan"),
+        (Language::Mk, "Надзор, ПОДЖОЛТЕН", "Nad·zor, POD·ŽOLTEN"),
+        (
+            Language::Sr,
+            "надживети, конјунктура",
+            "nad·živeti, kon·junktura",
+        ),
+    ];
+    for (lang, src, latin) in cases {
+        let trasliterator = Scholarly::new(lang).unwrap();
+        assert_eq!(latin, trasliterator.to_latin(src), "{:?}", lang);
+        assert_eq!(src, trasliterator.from_latin(latin), "{:?}", lang);
+    }
+
+    assert!(Scholarly::new(Language::Kz).is_none());
+    assert!(scholarly_rules(Language::Kz).is_none());
+}
+
+#[test]
+fn test_serbian_official() {
+    let trasliterator = SerbianOfficial::new();
+    assert!(trasliterator.as_ref().analyze().many_to_one.is_empty());
+
+    let src = "Љубав, ЊЕГОШ, Џемпер, Ђурђевак, Ћуприја, Чачак, Шабац, Жабаљ";
+    let res = trasliterator.to_latin(src);
+    assert_eq!(
+        "Ljubav, NJEGOŠ, Džemper, Đurđevak, Ćuprija, Čačak, Šabac, Žabalj",
+        res
+    );
+    assert_eq!(src, trasliterator.from_latin(&res));
+
+    // the digraphs of the exception list are two letters
+    let src = "Надживети, НАДЖИВЕЛИ, поджанр, инјекција, конјугација, Танјуг, ванјезички";
+    let res = trasliterator.to_latin(src);
+    assert_eq!(
+        "Nadživeti, NADŽIVELI, podžanr, injekcija, konjugacija, Tanjug, vanjezički",
+        res
+    );
+    assert_eq!(src, trasliterator.from_latin(&res));
+
+    // foreign words are kept
+    let res = trasliterator.from_latin("Instalirao sam Windows i Xerox, Müller je došao.");
+    assert_eq!("Инсталирао сам Windows и Xerox, Müller је дошао.", res);
+
+    assert!(matches!(
+        trasliterator.from_latin_cow("Већ је ћирилица."),
+        Cow::Borrowed(_)
+    ));
+    let mut output = String::new();
+    trasliterator.from_latin_into("Njiva", &mut output);
+    assert_eq!("Њива", output);
+    assert_eq!(
+        Ok(()),
+        trasliterator.verify_round_trip("Ђорђе Џонић, Љиљана Његош")
+    );
+    // foreign words are kept in both directions
+    let src = "Windows и Линукс";
+    assert_eq!(Ok(()), trasliterator.verify_round_trip(src));
+    assert!(trasliterator.as_ref().verify_round_trip(src).is_err());
+    assert_eq!("Serbian Official", Standard::SerbianOfficial.to_string());
+
+    let trasliterator = SerbianOfficial::new().with_unmapped(UnmappedPolicy::Replace("?".into()));
+    assert_eq!("Wifi ?aj ?чај?", trasliterator.from_latin("Wifi Çaj «čaj»"));
+    let trasliterator = SerbianOfficial::new().with_unmapped(UnmappedPolicy::Remove);
+    assert_eq!("2024", trasliterator.from_latin_cow("«2024»"));
+}
+
+#[test]
+fn test_kazakh_2021() {
+    let trasliterator = Kazakh2021::new();
+
+    let res = trasliterator.to_latin("Әлемнің, Ғалым, Ұлы, Үкімет, Іле, Айгүл, Иманғали");
+    assert_eq!("Älemnıñ, Ğalym, Ūly, Ükımet, Ile, Aigül, İmanğali", res);
+
+    // и and й are i next to back vowels too
+    let res = trasliterator.to_latin("Жайық, Ыбырай, Қиын");
+    assert_eq!("Jaiyq, Ybyrai, Qiyn", res);
+
+    // Russian loanwords
+    let res = trasliterator
+        .to_latin("Юрий, Новый, Щука, Цирк, Объект, Пьеса, Пальто, Ёлка, Яблоко, Экран");
+    assert_eq!(
+        "İuri, Novy, Şuka, Sirk, Obiekt, Piesa, Palto, İolka, İabloko, Ekran",
+        res
+    );
+    let res = trasliterator.to_latin("отец, концерт, ящик, борщ, жёлтый, брошюра, Чаща");
+    assert_eq!("otets, konsert, iaşşik, borş, jolty, broşura, Çaşşa", res);
+
+    assert_eq!(
+        "İURİ, QAZAQSTAN, OBİEKT, OTETS, ŞOTKA",
+        trasliterator.to_latin("ЮРИЙ, ҚАЗАҚСТАН, ОБЪЕКТ, ОТЕЦ, ЩЁТКА")
+    );
+    assert_eq!("Kazakh 2021", Standard::Kazakh2021.to_string());
+}
+
+#[test]
+fn test_uzbek_official() {
+    let trasliterator = UzbekOfficial::new();
+
+    let res = trasliterator.to_latin("Ўзбекистон, Ғалла, Қарши, Хива, Ҳаёт, Шаҳар, Чорсу, Йўл");
+    assert_eq!(
+        "Oʻzbekiston, Gʻalla, Qarshi, Xiva, Hayot, Shahar, Chorsu, Yoʻl",
+        res
+    );
+
+    // е, ц, the signs and с before ҳ
+    let res =
+        trasliterator.to_latin("Европа, Оила, Шеър, Цирк, Лицей, Станция, Исҳоқ, Асъор, Пальто");
+    assert_eq!(
+        "Yevropa, Oila, Sheʼr, Sirk, Litsey, Stansiya, Isʼhoq, Asʼor, Palto",
+        res
+    );
+
+    let res = trasliterator.from_latin("Oʻzbekiston, Gʻalla, Qarshi, Xiva, Hayot, Yoʻl");
+    assert_eq!("Ўзбекистон, Ғалла, Қарши, Хива, Ҳаёт, Йўл", res);
+
+    let res = trasliterator.from_latin("Yevropa, ekran, Sheʼr, Isʼhoq, Toshkent");
+    assert_eq!("Европа, экран, Шеър, Исҳоқ, Тошкент", res);
+
+    // the common substitutes of the apostrophes
+    let res = trasliterator.from_latin("O'zbekiston, g`alla, O‘g‘il, Is'hoq, she'r, 'salom'");
+    assert_eq!("Ўзбекистон, ғалла, Ўғил, Исҳоқ, шеър, 'салом'", res);
+
+    assert_eq!("ЎЗБЕКИСТОН", trasliterator.from_latin("OʻZBEKISTON"));
+    assert_eq!(
+        Cow::Borrowed("Ўзбекистон"),
+        trasliterator.from_latin_cow("Ўзбекистон")
+    );
+    assert_eq!("Uzbek Official", Standard::UzbekOfficial.to_string());
+
+    assert_eq!("Wифи Çай", trasliterator.from_latin("Wifi Çay"));
+    let trasliterator = UzbekOfficial::new().with_unmapped(UnmappedPolicy::Replace("?".into()));
+    assert_eq!("?ифи ?ай", trasliterator.from_latin("Wifi Çay"));
+    let trasliterator = UzbekOfficial::new().with_unmapped(UnmappedPolicy::Remove);
+    assert_eq!("2024", trasliterator.from_latin_cow("«2024»"));
+}
+
+#[test]
+fn test_mongolian_mns_5217() {
+    let trasliterator = MongolianMns5217::new();
+
+    let res = trasliterator.to_latin("Монгол Улс, Өвөрхангай, Үндсэн хууль, Цэцэрлэг, Жаргал");
+    assert_eq!(
+        "Mongol Uls, Övörkhangai, Ündsen khuuli, Tsetserleg, Jargal",
+        res
+    );
+
+    let res = trasliterator.to_latin("Ерөнхийлөгч, Ёс, Юм, Ямаа, Шинэ, Щётка, Хувьцаа, Цагаан");
+    assert_eq!(
+        "Yerönkhiilögch, Yos, Yum, Yamaa, Shine, Shyotka, Khuvitsaa, Tsagaan",
+        res
+    );
+
+    assert_eq!("ÖVÖRKHANGAI", trasliterator.to_latin("ӨВӨРХАНГАЙ"));
+
+    let trasliterator = MongolianMns5217::ascii();
+    let res = trasliterator.to_latin("Өвөрхангай, Үндсэн хууль, Хөвсгөл");
+    assert_eq!("Ovorkhangai, Undsen khuuli, Khovsgol", res);
+
+    assert_eq!("MNS 5217:2012", Standard::MongolianMns5217.to_string());
+
+    assert_eq!(
+        Some(Standard::MongolianMns5217),
+        Standard::national(Language::Mn)
+    );
+    assert_eq!(
+        Some(Standard::Passport2013),
+        Standard::national(Language::Ru)
+    );
+    assert_eq!(None, Standard::national(Language::Tt));
+    let trasliterator = Standard::national(Language::Mn)
+        .and_then(Standard::transliterator)
+        .unwrap();
+    assert_eq!("Övörkhangai", trasliterator.convert("Өвөрхангай", false));
+}
+
+const RU_LETTERS: &str = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+const BY_LETTERS: &str = "абвгдеёжзійклмнопрстуўфхцчшыьэюя";
+const UA_LETTERS: &str = "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя";
+
+/// Any sequence of the letters in any case, spaces and punctuation.
+fn any_text(letters: &'static str) -> impl Strategy<Value = String> {
+    let chars: Vec<char> = letters
+        .chars()
+        .flat_map(|c| c.to_uppercase().chain(Some(c)))
+        .chain(" ,-.\n".chars())
+        .collect();
+    let lowercase: Vec<char> = letters.chars().chain(" ,-.\n".chars()).collect();
+
+    prop_oneof![
+        prop::collection::vec(prop::sample::select(chars), 0..24),
+        prop::collection::vec(prop::sample::select(lowercase), 0..24),
+    ]
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Checks that the text contains a sequence which GOST 7.79 System B does not restore,
+/// see [`Gost779B`]. The conditions are written out by hand: the sequences whose
+/// Latin form is the one of another letter or sign, and a sign in another case than
+/// the letter after it, or at the end of a word than the letter before it.
+fn gost779b_lossy(lang: Language, src: &str) -> bool {
+    let merged: &[&str] = match lang {
+        Language::Ru => &["еъ", "еь", "ьъ", "ьь"],
+        Language::By => &["еь", "уь"],
+        _ => &["гь"],
+    };
+    let lower = src.to_lowercase();
+    if merged.iter().any(|sequence| lower.contains(sequence)) {
+        return true;
+    }
+    let is_sign = |c: char| "ъьЪЬ".contains(c);
+    let chars: Vec<char> = src.chars().collect();
+    chars.iter().enumerate().any(|(i, &c)| {
+        if !is_sign(c) {
+            return false;
+        }
+        let next = chars[i + 1..].iter().find(|&&c| !is_sign(c));
+        let previous = chars[..i].iter().rev().find(|&&c| !is_sign(c));
+        let upper = match next.filter(|c| c.is_alphabetic()) {
+            Some(next) => next.is_uppercase(),
+            None => previous.is_some_and(|c| c.is_uppercase()),
+        };
+        c.is_uppercase() != upper
+    })
+}
+
+/// Checks that the case of a sign is lost in GOST 7.79 System A, see [`Gost779A`]:
+/// a sign takes the case of the letter right after it, otherwise of the letter
+/// right before it, and is small after another sign or at the start of the text.
+fn gost779a_lossy(src: &str) -> bool {
+    let is_sign = |c: char| "ъьЪЬ".contains(c);
+    let chars: Vec<char> = src.chars().collect();
+    chars.iter().enumerate().any(|(i, &c)| {
+        if !is_sign(c) {
+            return false;
+        }
+        let next = chars
+            .get(i + 1)
+            .filter(|&&c| c.is_alphabetic() && !is_sign(c));
+        let upper = match next {
+            Some(next) => next.is_uppercase(),
+            None => i > 0 && !is_sign(chars[i - 1]) && chars[i - 1].is_uppercase(),
+        };
+        c.is_uppercase() != upper
+    })
+}
+
+/// Words of the letters in lowercase, title case or capitals, separated by punctuation.
+/// A sign follows only a consonant and never starts a word, as in real texts.
+fn cyrillic_text(
+    consonants: &'static str,
+    vowels: &'static str,
+    signs: &'static str,
+) -> impl Strategy<Value = String> {
+    let consonant = prop::sample::select(consonants.chars().collect::<Vec<_>>());
+    let vowel = prop::sample::select(vowels.chars().collect::<Vec<_>>());
+    let sign = prop::sample::select(signs.chars().collect::<Vec<_>>());
+
+    let letter = prop_oneof![
+        consonant.clone().prop_map(String::from),
+        vowel.prop_map(String::from),
+    ];
+    let syllable = prop_oneof![
+        letter.clone(),
+        (consonant, sign).prop_map(|(c, s)| format!("{}{}", c, s)),
+    ];
+    let word =
+        (letter, prop::collection::vec(syllable, 0..8), 0..3u8).prop_map(|(first, rest, case)| {
+            let word = first + &rest.concat();
+            match case {
+                0 => word,
+                1 => {
+                    let mut chars = word.chars();
+                    chars
+                        .next()
+                        .into_iter()
+                        .flat_map(char::to_uppercase)
+                        .chain(chars)
+                        .collect()
+                }
+                _ => word.to_uppercase(),
+            }
+        });
+    let separator = prop::sample::select(vec![" ", ", ", " - ", ".\n"]);
+
+    prop::collection::vec((word, separator), 1..8).prop_map(|words| {
+        words
+            .into_iter()
+            .map(|(word, separator)| word + separator)
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn prop_round_trip_gost779b_ru(src in any_text(RU_LETTERS)) {
+        let res = Gost779B::new(Language::Ru).verify_round_trip(&src);
+        if !gost779b_lossy(Language::Ru, &src) {
+            prop_assert!(res.is_ok(), "{:?}", res);
+        }
+    }
+
+    #[test]
+    fn prop_round_trip_gost779b_by(src in any_text(BY_LETTERS)) {
+        let res = Gost779B::new(Language::By).verify_round_trip(&src);
+        if !gost779b_lossy(Language::By, &src) {
+            prop_assert!(res.is_ok(), "{:?}", res);
+        }
+    }
+
+    #[test]
+    fn prop_round_trip_gost779b_ua(src in any_text(UA_LETTERS)) {
+        let res = Gost779B::new(Language::Ua).verify_round_trip(&src);
+        if !gost779b_lossy(Language::Ua, &src) {
+            prop_assert!(res.is_ok(), "{:?}", res);
+        }
+    }
+
+    #[test]
+    fn prop_round_trip_gost779a(
+        src in cyrillic_text(
+            "бвгджзйклмнпрстфхцчшщўґєіїѓѕјќљњџђћѣѳѵѫӑӓәӕғҕһӗӝӂӟӡӣӥӄҝҡҟқңӊҥӧөӫҧҫҭӯӱӳүұҳҵӵҷӌҹҩӹ",
+            "аеёиоуыэюя",
+            "ъь",
+        )
+    ) {
+        prop_assert_eq!(Gost779A::new().verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_gost779a_signs(src in any_text(RU_LETTERS)) {
+        let res = Gost779A::new().verify_round_trip(&src);
+        prop_assert_eq!(res.is_ok(), !gost779a_lossy(&src), "{:?}", res);
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_ru(
+        src in cyrillic_text("бвгджзйклмнпрстфхцчшщѳ", "аеёиоуыэюяіѣѵ", "ъь")
+    ) {
+        let trasliterator = Scholarly::new(Language::Ru).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_ua(
+        src in cyrillic_text("бвгґджзйклмнпрстфхцчшщ", "аеєиіїоуюя", "ь'")
+    ) {
+        let trasliterator = Scholarly::new(Language::Ua).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_by(
+        src in cyrillic_text("бвгґджзйклмнпрстўфхцчш", "аеёіоуыэюя", "ь'")
+    ) {
+        let trasliterator = Scholarly::new(Language::By).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_bg(
+        src in cyrillic_text("бвгджзйклмнпрстфхцчшщ", "аеиоуъюяѣѫ", "ь")
+    ) {
+        let trasliterator = Scholarly::new(Language::Bg).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_mk(
+        src in cyrillic_text("бвгдѓжзѕјклљмнњпрстќфхцчџш", "аеиоу", "ј")
+    ) {
+        let trasliterator = Scholarly::new(Language::Mk).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+
+    #[test]
+    fn prop_round_trip_scholarly_sr(
+        src in cyrillic_text("бвгдђжзјклљмнњпрстћфхцчџш", "аеиоу", "ј")
+    ) {
+        let trasliterator = Scholarly::new(Language::Sr).unwrap();
+        prop_assert_eq!(trasliterator.verify_round_trip(&src), Ok(()));
+    }
+}
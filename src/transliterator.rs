@@ -1,41 +1,502 @@
 use super::CharsMapping;
 
-use std::cmp::Ordering;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Read, Write};
 
+use crate::alignment::Alignment;
+use crate::builder::TransliteratorBuilder;
+use crate::candidates::Candidate;
+use crate::casing::{to_title, Case};
+use crate::error::{RoundTripError, TranslitError};
+use crate::ngram::LetterModel;
+use crate::script::{is_latin, is_source_letter, needs_rule};
+use crate::trie::Trie;
+use crate::unmapped::UnmappedPolicy;
+
+use crate::ala_lc;
+use crate::analysis;
+use crate::bgn_pcgn;
 use crate::bulgarian;
 use crate::gost779;
+use crate::kazakh;
 use crate::macedonian;
+use crate::mongolian;
 use crate::order_n_995;
 use crate::passport2013;
+use crate::scholarly;
+use crate::serbian;
+use crate::stream;
+use crate::ukrainian;
+use crate::uzbek;
 
 /// The contract for transliteration in the Latin alphabet
 pub trait ToLatin {
     fn to_latin(&self, src: &str) -> String;
+
+    /// Appends the transliteration of `src` to `output`.
+    ///
+    /// The schemes of this crate do not allocate, so one buffer can be reused in a loop.
+    fn to_latin_into(&self, src: &str, output: &mut String) {
+        output.push_str(&self.to_latin(src));
+    }
+
+    /// Writes the transliteration of `src` to `output`.
+    fn to_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        output.write_str(&self.to_latin(src))
+    }
+
+    /// Transliterates `src`, the schemes of this crate borrow it
+    /// if there is nothing to transliterate.
+    fn to_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self.to_latin(src))
+    }
+
+    /// Transliterates UTF-8 text from `reader` to `writer`.
+    ///
+    /// The default implementation converts the input line by line,
+    /// the schemes of this crate use a buffer of a fixed size instead.
+    fn to_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        stream::convert_lines(reader, writer, |line| self.to_latin(line))
+    }
 }
 
 /// The contract for transliteration from Latin alphabet
 pub trait FromLatin {
+    #[allow(clippy::wrong_self_convention)]
     fn from_latin(&self, src: &str) -> String;
+
+    /// Appends the transliteration of `src` to `output`.
+    ///
+    /// The schemes of this crate do not allocate, so one buffer can be reused in a loop.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_latin_into(&self, src: &str, output: &mut String) {
+        output.push_str(&self.from_latin(src));
+    }
+
+    /// Writes the transliteration of `src` to `output`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        output.write_str(&self.from_latin(src))
+    }
+
+    /// Transliterates `src`, the schemes of this crate borrow it
+    /// if there is nothing to transliterate.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        Cow::Owned(self.from_latin(src))
+    }
+
+    /// Transliterates UTF-8 text from `reader` to `writer`.
+    ///
+    /// The default implementation converts the input line by line,
+    /// the schemes of this crate read it in chunks instead.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        stream::convert_lines(reader, writer, |line| self.from_latin(line))
+    }
+}
+
+/// Implements `ToLatin` for a scheme which keeps its `Transliterator` in the field `translit`.
+macro_rules! impl_to_latin {
+    ($scheme:ty) => {
+        impl $scheme {
+            /// Sets what to do with the characters which have no rule, they are kept by default.
+            pub fn with_unmapped(self, policy: UnmappedPolicy) -> Self {
+                Self {
+                    translit: self.translit.with_unmapped(policy),
+                }
+            }
+
+            /// Transliterates in the Latin alphabet, Cyrillic characters without a rule
+            /// are an error.
+            /// See [`Transliterator::convert_strict`].
+            pub fn to_latin_strict(&self, src: &str) -> Result<String, TranslitError> {
+                self.translit.convert_strict(src, false)
+            }
+
+            /// Transliterates in the Latin alphabet and returns the output with the segments
+            /// of the input it was produced from. See [`Transliterator::convert_with_alignment`].
+            pub fn to_latin_with_alignment(&self, src: &str) -> Alignment {
+                self.translit.convert_with_alignment(src, false)
+            }
+        }
+
+        impl AsRef<Transliterator> for $scheme {
+            fn as_ref(&self) -> &Transliterator {
+                &self.translit
+            }
+        }
+
+        impl From<$scheme> for TransliteratorBuilder {
+            fn from(scheme: $scheme) -> Self {
+                scheme.translit.into()
+            }
+        }
+
+        impl ToLatin for $scheme {
+            fn to_latin(&self, src: &str) -> String {
+                self.translit.to_latin(src)
+            }
+
+            fn to_latin_into(&self, src: &str, output: &mut String) {
+                self.translit.to_latin_into(src, output)
+            }
+
+            fn to_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+                self.translit.to_latin_fmt(src, output)
+            }
+
+            fn to_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+                self.translit.to_latin_cow(src)
+            }
+
+            fn to_latin_stream(
+                &self,
+                reader: &mut dyn Read,
+                writer: &mut dyn Write,
+            ) -> io::Result<()> {
+                self.translit.to_latin_stream(reader, writer)
+            }
+        }
+    };
+}
+
+/// Implements `FromLatin` for a scheme which keeps its `Transliterator` in the field `translit`.
+macro_rules! impl_from_latin {
+    ($scheme:ty) => {
+        impl $scheme {
+            /// Transliterates from the Latin alphabet, Latin letters without a rule are an error.
+            /// See [`Transliterator::convert_strict`].
+            pub fn from_latin_strict(&self, src: &str) -> Result<String, TranslitError> {
+                self.translit.convert_strict(src, true)
+            }
+
+            /// Transliterates from the Latin alphabet and returns the output with the segments
+            /// of the input it was produced from. See [`Transliterator::convert_with_alignment`].
+            pub fn from_latin_with_alignment(&self, src: &str) -> Alignment {
+                self.translit.convert_with_alignment(src, true)
+            }
+
+            /// Checks that `src` is restored by the transliteration in the Latin alphabet and back.
+            /// See [`Transliterator::verify_round_trip`].
+            pub fn verify_round_trip(&self, src: &str) -> Result<(), RoundTripError> {
+                self.translit.verify_round_trip(src)
+            }
+        }
+
+        impl FromLatin for $scheme {
+            fn from_latin(&self, src: &str) -> String {
+                self.translit.from_latin(src)
+            }
+
+            fn from_latin_into(&self, src: &str, output: &mut String) {
+                self.translit.from_latin_into(src, output)
+            }
+
+            fn from_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+                self.translit.from_latin_fmt(src, output)
+            }
+
+            fn from_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+                self.translit.from_latin_cow(src)
+            }
+
+            fn from_latin_stream(
+                &self,
+                reader: &mut dyn Read,
+                writer: &mut dyn Write,
+            ) -> io::Result<()> {
+                self.translit.from_latin_stream(reader, writer)
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_latin_candidates {
+    ($scheme:ty, $model:expr) => {
+        impl $scheme {
+            /// Returns up to `limit` Cyrillic texts which are transliterated to `src`,
+            /// the most likely first. See [`Transliterator::candidates`].
+            pub fn from_latin_candidates(&self, src: &str, limit: usize) -> Vec<Candidate> {
+                self.translit.candidates(src, $model, limit)
+            }
+        }
+    };
 }
 
 /// Support Languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Language {
+    /// Russian.
     Ru,
+    /// Belarusian.
     By,
+    /// Ukrainian.
     Ua,
+    /// Bulgarian.
+    Bg,
+    /// Macedonian.
+    Mk,
+    /// Serbian.
+    Sr,
+    /// Kazakh.
+    Kz,
+    /// Kyrgyz.
+    Kg,
+    /// Tajik.
+    Tj,
+    /// Uzbek.
+    Uz,
+    /// Turkmen.
+    Tm,
+    /// Azerbaijani.
+    Az,
+    /// Mongolian.
+    Mn,
+    /// Buryat.
+    Bua,
+    /// Moldavian.
+    Md,
+    /// Abkhaz.
+    Ab,
+    /// Bashkir.
+    Ba,
+    /// Tatar.
+    Tt,
+    /// Chuvash.
+    Cv,
+    /// Yakut.
+    Sah,
+    /// Altai.
+    Alt,
+    /// Mari.
+    Chm,
+    /// Udmurt.
+    Udm,
+}
+
+impl Language {
+    /// All supported languages.
+    pub const ALL: &'static [Language] = &[
+        Language::Ru,
+        Language::By,
+        Language::Ua,
+        Language::Bg,
+        Language::Mk,
+        Language::Sr,
+        Language::Kz,
+        Language::Kg,
+        Language::Tj,
+        Language::Uz,
+        Language::Tm,
+        Language::Az,
+        Language::Mn,
+        Language::Bua,
+        Language::Md,
+        Language::Ab,
+        Language::Ba,
+        Language::Tt,
+        Language::Cv,
+        Language::Sah,
+        Language::Alt,
+        Language::Chm,
+        Language::Udm,
+    ];
+}
+
+/// Condition on the text around the source string of a [`Rule`].
+///
+/// Letters are compared regardless of case, list them in lower case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    /// Any text.
+    Any,
+    /// The beginning of a word before the source string, the end of a word after it.
+    WordBoundary,
+    /// The neighbouring character is one of the listed letters.
+    OneOf(&'static str),
+    /// The neighbouring character is not one of the listed letters or there is none.
+    NoneOf(&'static str),
+}
+
+impl Condition {
+    pub(crate) fn is_any(self) -> bool {
+        self == Condition::Any
+    }
+
+    /// Checks the neighbouring character, `in_word` tells whether it is a part of the word.
+    fn check(self, neighbour: Option<char>, in_word: bool) -> bool {
+        let listed = |letters: &str| {
+            neighbour
+                .and_then(|c| c.to_lowercase().next())
+                .is_some_and(|c| letters.contains(c))
+        };
+
+        match self {
+            Condition::Any => true,
+            Condition::WordBoundary => !in_word,
+            Condition::OneOf(letters) => listed(letters),
+            Condition::NoneOf(letters) => !listed(letters),
+        }
+    }
+}
+
+/// The apostrophes which can be a part of a word.
+pub(crate) fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’' || c == 'ʼ'
+}
+
+/// Letters are parts of words, an apostrophe is a part of a word between two letters
+/// and a quotation mark otherwise.
+pub(crate) fn is_word_char(before: Option<char>, c: char, after: Option<char>) -> bool {
+    c.is_alphabetic()
+        || is_apostrophe(c)
+            && before.is_some_and(char::is_alphabetic)
+            && after.is_some_and(char::is_alphabetic)
+}
+
+/// Splits the text into words and the parts between them, see [`words`].
+pub(crate) struct Words<'a> {
+    rest: &'a str,
+    before: Option<char>,
+}
+
+/// Returns the words of the text and the parts between them in turn,
+/// `true` marks a word.
+pub(crate) fn words(text: &str) -> Words<'_> {
+    Words {
+        rest: text,
+        before: None,
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (bool, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices().peekable();
+        let mut in_word = None;
+
+        while let Some((index, c)) = chars.next() {
+            let after = chars.peek().map(|&(_, c)| c);
+            let is_word = is_word_char(self.before, c, after);
+            match in_word {
+                Some(in_word) if in_word != is_word => {
+                    let (part, rest) = self.rest.split_at(index);
+                    self.rest = rest;
+                    return Some((in_word, part));
+                }
+                _ => in_word = Some(is_word),
+            }
+            self.before = Some(c);
+        }
+
+        in_word.map(|in_word| (in_word, std::mem::take(&mut self.rest)))
+    }
+}
+
+/// The transliteration rule: `source` is replaced with `translit`
+/// if the text around it meets the conditions `before` and `after`.
+///
+/// The conditions are checked on the Cyrillic text only,
+/// transliteration from the Latin alphabet ignores them.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{Condition, Rule, ToLatin, Transliterator};
+/// let rules = vec![
+///     Rule::new("ц", "c").after(Condition::OneOf("еи")),
+///     Rule::new("ц", "cz"),
+///     Rule::new("и", "i"),
+///     Rule::new("р", "r"),
+///     Rule::new("а", "a"),
+/// ];
+///
+/// let trasliterator = Transliterator::from_rules(rules);
+/// assert_eq!("czar cir", trasliterator.to_latin("цар цир"));
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub source: &'static str,
+    pub translit: &'static str,
+    pub before: Condition,
+    pub after: Condition,
+}
+
+impl Rule {
+    /// Creates a rule which is applied in any context.
+    pub const fn new(source: &'static str, translit: &'static str) -> Self {
+        Rule {
+            source,
+            translit,
+            before: Condition::Any,
+            after: Condition::Any,
+        }
+    }
+
+    /// Sets the condition on the text before the source string.
+    pub const fn before(self, before: Condition) -> Self {
+        Rule { before, ..self }
+    }
+
+    /// Sets the condition on the text after the source string.
+    pub const fn after(self, after: Condition) -> Self {
+        Rule { after, ..self }
+    }
+
+    fn applies(&self, before: &str, after: &str) -> bool {
+        let mut back = before.chars().rev();
+        let (previous, beyond) = (back.next(), back.next());
+        let mut forward = after.chars();
+        let (next, further) = (forward.next(), forward.next());
+
+        let in_word_before =
+            previous.is_some_and(|c| is_word_char(beyond, c, self.source.chars().next()));
+        let in_word_after =
+            next.is_some_and(|c| is_word_char(self.source.chars().next_back(), c, further));
+
+        self.before.check(previous, in_word_before) && self.after.check(next, in_word_after)
+    }
+}
+
+impl From<(&'static str, &'static str)> for Rule {
+    fn from((source, translit): (&'static str, &'static str)) -> Self {
+        Rule::new(source, translit)
+    }
 }
 
 /// The `Transliterator` struct allows for the transliteration
 /// of the string of characters of Cyrillic alphabet UTF-8 to Latin alphabet
 /// and back.
 ///
+/// The input is scanned once from left to right. At every position the longest
+/// key of the table is replaced, characters without a key are copied as is.
+/// Text that has already been produced is never read again, so the order
+/// of the rules with different keys does not matter.
+#[derive(Debug, Clone)]
 pub struct Transliterator {
-    rules: CharsMapping,
+    rules: Vec<Rule>,
+    forward: Keys,
+    backward: Keys,
+    case_aware: bool,
+    unmapped: UnmappedPolicy,
 }
 
 impl Transliterator {
     /// Creates a new `Transliterator` with transliteration table
     ///
+    /// If a key occurs in the table more than once the first entry wins.
+    /// Entries with an empty Latin string are not used for the transliteration
+    /// from the Latin alphabet.
+    ///
+    /// Keys of two or more letters are also recognized in title case and in capitals,
+    /// a capital letter inside a word written in capitals produces capitals.
+    ///
     /// Examples
     ///
     /// ```rust
@@ -57,39 +518,371 @@ impl Transliterator {
     ///
     /// ```
     pub fn new(custom_rules: CharsMapping) -> Self {
-        let mut table = custom_rules;
-        fn compare_len(left: &str, right: &str) -> Ordering {
-            if left.len() == right.len() {
-                Ordering::Equal
-            } else if left.len() > right.len() {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            }
+        Self::from_rules(custom_rules.into_iter().map(Rule::from).collect())
+    }
+
+    /// Creates a new `Transliterator` with context-sensitive rules.
+    ///
+    /// Rules with the same source string are tried in the order of the list,
+    /// the first one whose conditions are met wins.
+    /// If none of them applies, a shorter key is tried.
+    pub fn from_rules(rules: Vec<Rule>) -> Self {
+        Self::with_options(rules, true, UnmappedPolicy::Keep)
+    }
+
+    pub(crate) fn with_options(
+        rules: Vec<Rule>,
+        case_aware: bool,
+        unmapped: UnmappedPolicy,
+    ) -> Self {
+        let forward = Keys::new(rules.iter().map(|rule| rule.source), case_aware);
+        let backward = Keys::new(rules.iter().map(|rule| rule.translit), case_aware);
+
+        Self {
+            rules,
+            forward,
+            backward,
+            case_aware,
+            unmapped,
         }
-        // sort by Latin string
-        table.sort_by(|a, b| compare_len(b.1, a.1));
+    }
+
+    /// The rules of the transliteration in the order they are tried.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
 
-        Self { rules: table }
+    /// Returns `false` if the case of the output is not adjusted to the input,
+    /// see [`TransliteratorBuilder::case_aware`](crate::TransliteratorBuilder::case_aware).
+    pub fn is_case_aware(&self) -> bool {
+        self.case_aware
+    }
+
+    /// The policy for the characters which have no rule.
+    pub fn unmapped(&self) -> &UnmappedPolicy {
+        &self.unmapped
+    }
+
+    /// Sets what to do with the characters which have no rule, they are kept by default.
+    pub fn with_unmapped(mut self, policy: UnmappedPolicy) -> Self {
+        self.unmapped = policy;
+        self
     }
 
     /// Transliterate input string.
     pub fn convert(&self, src: &str, invert: bool) -> String {
-        let mut input = src.to_owned();
+        let mut output = String::with_capacity(src.len());
+        self.convert_into(src, invert, &mut output);
+        output
+    }
+
+    /// Transliterates input string and appends the result to `output`.
+    ///
+    /// Reusing one buffer for many strings avoids an allocation per call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{iternational_passport_2013_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// let mut buffer = String::new();
+    /// for name in &["Юрий", "Щукин"] {
+    ///     buffer.clear();
+    ///     trasliterator.convert_into(name, false, &mut buffer);
+    ///     assert!(buffer.is_ascii());
+    /// }
+    ///
+    /// ```
+    pub fn convert_into(&self, src: &str, invert: bool, output: &mut String) {
+        self.convert_fmt(src, invert, output)
+            .expect("writing to a `String` never fails");
+    }
+
+    /// Transliterates input string and writes the result to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the writer.
+    pub fn convert_fmt<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        invert: bool,
+        output: &mut W,
+    ) -> fmt::Result {
+        self.write_from(src, 0, invert, output)
+    }
+
+    /// Transliterates input string, Cyrillic characters without a rule are an error.
+    ///
+    /// In the transliteration from the Latin alphabet the Latin letters without a rule
+    /// are an error. Other characters, like "—" or "©", are copied as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns the list of all such characters with their byte offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use translit::{iternational_passport_2013_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// assert_eq!(trasliterator.convert_strict("Юрий", false).unwrap(), "Iurii");
+    ///
+    /// let err = trasliterator.convert_strict("Київ", false).unwrap_err();
+    /// assert_eq!(err.unmapped(), &[(4, 'ї')]);
+    ///
+    /// ```
+    pub fn convert_strict(&self, src: &str, invert: bool) -> Result<String, TranslitError> {
+        let mut output = String::with_capacity(src.len());
+        let mut unmapped = Vec::new();
+        let mut pos = 0;
 
-        for elem in self.rules.iter() {
-            let (source_char, translit_char) = (elem.0, elem.1);
+        while pos < src.len() {
+            let step = self
+                .step(src, pos, invert, &mut output)
+                .expect("writing to a `String` never fails");
+            if let Step::Char(c) = step {
+                if is_source_letter(c, invert) {
+                    unmapped.push((pos, c));
+                }
+                output.push(c);
+            }
+            pos += step.len();
+        }
 
-            input = {
-                if invert {
-                    input.replace(translit_char, source_char)
+        if unmapped.is_empty() {
+            Ok(output)
+        } else {
+            Err(TranslitError::new(unmapped))
+        }
+    }
+
+    /// Transliterates input string, the input is borrowed if it contains no key of the table
+    /// (and no letter without a rule, unless such letters are kept).
+    ///
+    /// ASCII input is checked without looking into the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use std::borrow::Cow;
+    /// use translit::{iternational_passport_2013_ru, Transliterator};
+    /// let trasliterator = Transliterator::new(iternational_passport_2013_ru());
+    /// assert!(matches!(trasliterator.convert_cow("ivanov@example.com", false), Cow::Borrowed(_)));
+    /// assert_eq!(trasliterator.convert_cow("Иванов", false), "Ivanov");
+    ///
+    /// ```
+    pub fn convert_cow<'a>(&self, src: &'a str, invert: bool) -> Cow<'a, str> {
+        let keys = self.keys(invert);
+        let keep = self.unmapped.is_keep();
+        let changes = |c: char| !keep && needs_rule(c, invert);
+
+        let start = if src
+            .bytes()
+            .all(|b| b.is_ascii() && !keys.starts_with_ascii(b) && !changes(char::from(b)))
+        {
+            None
+        } else {
+            src.char_indices()
+                .find(|&(pos, c)| changes(c) || keys.trie.prefixes(&src[pos..]).next().is_some())
+                .map(|(pos, _)| pos)
+        };
+
+        match start {
+            None => Cow::Borrowed(src),
+            Some(start) => {
+                let mut output = String::with_capacity(src.len());
+                output.push_str(&src[..start]);
+                self.write_from(src, start, invert, &mut output)
+                    .expect("writing to a `String` never fails");
+                Cow::Owned(output)
+            }
+        }
+    }
+
+    fn write_from<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        mut pos: usize,
+        invert: bool,
+        output: &mut W,
+    ) -> fmt::Result {
+        while pos < src.len() {
+            pos += self.write_step(src, pos, invert, output)?;
+        }
+
+        Ok(())
+    }
+
+    /// Transliterates the text at `pos` and returns the number of consumed bytes.
+    pub(crate) fn write_step<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        pos: usize,
+        invert: bool,
+        output: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        let step = self.step(src, pos, invert, output)?;
+        if let Step::Char(c) = step {
+            self.write_char(c, invert, output)?;
+        }
+        Ok(step.len())
+    }
+
+    /// Transliterates the text at `pos` with the rule which applies there.
+    /// A character without a rule is returned and not written to `output`.
+    ///
+    /// The rules look at two characters before `pos` and at two characters after the key.
+    pub(crate) fn step<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        pos: usize,
+        invert: bool,
+        output: &mut W,
+    ) -> Result<Step, fmt::Error> {
+        let keys = self.keys(invert);
+        let (before, rest) = src.split_at(pos);
+
+        let mut found = None;
+        for (len, entry) in keys.trie.prefixes(rest) {
+            let rule = keys.entries[entry]
+                .iter()
+                .find(|&&(index, _)| invert || self.rules[index].applies(before, &rest[len..]));
+            if let Some(&(index, case)) = rule {
+                found = Some((len, index, case));
+            }
+        }
+
+        match found {
+            Some((len, index, case)) => {
+                let rule = &self.rules[index];
+                let case = if self.case_aware {
+                    case.resolve(&rest[..len], before, &rest[len..])
                 } else {
-                    input.replace(source_char, translit_char)
+                    case
+                };
+                case.write(output, if invert { rule.source } else { rule.translit })?;
+                Ok(Step::Rule(len))
+            }
+            None => Ok(Step::Char(rest.chars().next().unwrap_or_default())),
+        }
+    }
+
+    /// Writes a character which has no rule according to the policy for unmapped letters.
+    pub(crate) fn write_char<W: fmt::Write + ?Sized>(
+        &self,
+        c: char,
+        invert: bool,
+        output: &mut W,
+    ) -> fmt::Result {
+        if needs_rule(c, invert) {
+            self.unmapped.write(c, output)
+        } else {
+            output.write_char(c)
+        }
+    }
+
+    /// The length in characters of the longest key in the given direction.
+    pub(crate) fn max_key_len(&self, invert: bool) -> usize {
+        self.keys(invert).max_len
+    }
+
+    fn keys(&self, invert: bool) -> &Keys {
+        if invert {
+            &self.backward
+        } else {
+            &self.forward
+        }
+    }
+}
+
+/// The result of [`Transliterator::step`].
+pub(crate) enum Step {
+    /// A rule was applied to the key of the given byte length.
+    Rule(usize),
+    /// The character has no rule.
+    Char(char),
+}
+
+impl Step {
+    /// The number of consumed bytes.
+    pub(crate) fn len(&self) -> usize {
+        match *self {
+            Step::Rule(len) => len,
+            Step::Char(c) => c.len_utf8(),
+        }
+    }
+}
+
+/// Keys of one side of the table with their case variants.
+/// Every key refers to the list of rules which have this key.
+#[derive(Debug, Clone)]
+struct Keys {
+    trie: Trie,
+    entries: Vec<Vec<(usize, Case)>>,
+    max_len: usize,
+    /// Bit `b` is set if a key starts with the ASCII character `b`.
+    ascii_starts: u128,
+}
+
+impl Keys {
+    fn new<'a, I>(keys: I, case_variants: bool) -> Self
+    where
+        I: Iterator<Item = &'a str> + Clone,
+    {
+        let mut result = Keys {
+            trie: Trie::new(),
+            entries: Vec::new(),
+            max_len: 0,
+            ascii_starts: 0,
+        };
+
+        for (index, key) in keys.clone().enumerate().filter(|(_, key)| !key.is_empty()) {
+            let entry = result.entry(key);
+            result.entries[entry].push((index, Case::Keep));
+        }
+
+        if !case_variants {
+            return result;
+        }
+
+        // case variants never hide the keys written in the table
+        let exact = result.entries.len();
+        for (index, key) in keys
+            .enumerate()
+            .filter(|(_, key)| key.chars().nth(1).is_some())
+        {
+            for (variant, case) in [
+                (to_title(key), Case::Title),
+                (key.to_uppercase(), Case::Upper),
+            ] {
+                let entry = result.entry(&variant);
+                if entry >= exact {
+                    result.entries[entry].push((index, case));
                 }
             }
         }
 
-        input
+        result
+    }
+
+    fn starts_with_ascii(&self, b: u8) -> bool {
+        self.ascii_starts & (1 << b) != 0
+    }
+
+    fn entry(&mut self, key: &str) -> usize {
+        self.max_len = self.max_len.max(key.chars().count());
+        if let Some(&first) = key.as_bytes().first().filter(|b| b.is_ascii()) {
+            self.ascii_starts |= 1 << first;
+        }
+        let entry = self.trie.get_or_insert(key, self.entries.len());
+        if entry == self.entries.len() {
+            self.entries.push(Vec::new());
+        }
+        entry
     }
 }
 
@@ -98,6 +891,26 @@ impl ToLatin for Transliterator {
     fn to_latin(&self, src: &str) -> String {
         self.convert(src, false)
     }
+
+    /// The wrapper on the method `convert_into` of transliteration in the Latin alphabet
+    fn to_latin_into(&self, src: &str, output: &mut String) {
+        self.convert_into(src, false, output)
+    }
+
+    /// The wrapper on the method `convert_fmt` of transliteration in the Latin alphabet
+    fn to_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        self.convert_fmt(src, false, output)
+    }
+
+    /// The wrapper on the method `convert_cow` of transliteration in the Latin alphabet
+    fn to_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        self.convert_cow(src, false)
+    }
+
+    /// The wrapper on the method `convert_stream` of transliteration in the Latin alphabet
+    fn to_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        self.convert_stream(reader, writer, false)
+    }
 }
 
 impl FromLatin for Transliterator {
@@ -105,6 +918,26 @@ impl FromLatin for Transliterator {
     fn from_latin(&self, src: &str) -> String {
         self.convert(src, true)
     }
+
+    /// The wrapper on the method `convert_into` of transliteration from the Latin alphabet
+    fn from_latin_into(&self, src: &str, output: &mut String) {
+        self.convert_into(src, true, output)
+    }
+
+    /// The wrapper on the method `convert_fmt` of transliteration from the Latin alphabet
+    fn from_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        self.convert_fmt(src, true, output)
+    }
+
+    /// The wrapper on the method `convert_cow` of transliteration from the Latin alphabet
+    fn from_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        self.convert_cow(src, true)
+    }
+
+    /// The wrapper on the method `convert_stream` of transliteration from the Latin alphabet
+    fn from_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        self.convert_stream(reader, writer, true)
+    }
 }
 
 /// Cyrillic transliteration table.
@@ -114,6 +947,22 @@ impl FromLatin for Transliterator {
 ///
 /// Check the possibility of transliteration is carried out at compile time
 ///
+/// Every [`Language`] of the annex of the standard has its own table,
+/// see [`gost779b`](crate::gost779b).
+///
+/// The Russian, Belarusian and Ukrainian text is restored from the Latin alphabet
+/// except for these sequences, [`verify_round_trip`](Gost779B::verify_round_trip) reports them:
+///
+/// * a sign after the letter whose transliteration followed by "`" is another letter:
+///   Russian and Belarusian `еь`, Russian `еъ` ("e`" is `э`), Belarusian `уь` ("u`" is `ў`),
+///   Ukrainian `гь` ("g`" is `ґ`);
+/// * two signs in Russian: `ьь` is "``" like `ъ`, `ьъ` is "```" like `ъь`;
+/// * the case of a sign, which has no case in the Latin alphabet: a sign is restored
+///   in the case of the next letter, or at the end of a word in the case of the last
+///   Latin letter before it. So a capital sign before a small letter is lost, and at
+///   the end of a word a capital sign after a small letter and a small sign after
+///   a capital written with one Latin letter: "Аь" is "A`" and is read back as "АЬ".
+///
 /// # Examples
 ///
 /// ```rust
@@ -124,6 +973,10 @@ impl FromLatin for Transliterator {
 /// let res = trasliterator.to_latin("Россия");
 /// assert_eq!("Rossiya", res);
 ///
+/// let trasliterator = Gost779B::new(Language::Kz);
+/// let res = trasliterator.to_latin("Қазақстан");
+/// assert_eq!("K`azak`stan", res);
+///
 /// ```
 pub struct Gost779B {
     translit: Transliterator,
@@ -131,30 +984,384 @@ pub struct Gost779B {
 
 impl Gost779B {
     pub fn new(lang: Language) -> Gost779B {
-        let table = match lang {
-            Language::Ru => gost779::gost779b_ru(),
-            Language::By => gost779::gost779b_by(),
-            Language::Ua => gost779::gost779b_ua(),
-        };
-
-        let translit = Transliterator::new(table);
+        let translit = Transliterator::from_rules(gost779::gost779b_rules(lang));
 
         Gost779B { translit }
     }
 }
 
-impl ToLatin for Gost779B {
-    fn to_latin(&self, src: &str) -> String {
-        self.translit.to_latin(src)
+impl_to_latin!(Gost779B);
+
+impl_from_latin!(Gost779B);
+
+/// Cyrillic transliteration table.
+/// implementation GOST 7.79 System A, ISO 9:1995.
+/// more details:
+/// [http://en.wikipedia.org/wiki/ISO_9](http://en.wikipedia.org/wiki/ISO_9).
+///
+/// Every Cyrillic letter of all languages of the standard has its own Latin letter
+/// with diacritics, so the transliteration from the Latin alphabet is lossless
+/// except for the case of `ъ` and `ь`: "ʺ" and "ʹ" have no case, and a sign is restored
+/// in the case of the letter right after it, otherwise of the letter right before it.
+/// So "Ъем" is read back as "ъем" and "подъЕзд" as "подЪЕзд".
+/// The Latin text is expected in the composed form (NFC).
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{FromLatin, Gost779A, ToLatin};
+/// let trasliterator = Gost779A::new();
+/// let res = trasliterator.to_latin("Щёлково");
+/// assert_eq!("Ŝëlkovo", res);
+/// assert_eq!("Щёлково", trasliterator.from_latin(&res));
+///
+/// ```
+pub struct Gost779A {
+    translit: Transliterator,
+}
+
+impl Gost779A {
+    pub fn new() -> Gost779A {
+        let translit = Transliterator::new(gost779::gost779a());
+
+        Gost779A { translit }
+    }
+}
+
+impl Default for Gost779A {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl_to_latin!(Gost779A);
+
+impl_from_latin!(Gost779A);
+
+/// Scientific (scholarly) transliteration of the Slavic studies.
+/// more details:
+/// [Scientific transliteration of Cyrillic](https://en.wikipedia.org/wiki/Scientific_transliteration_of_Cyrillic)
+///
+/// Tables for [`Language::Ru`], [`Language::Ua`], [`Language::By`], [`Language::Bg`],
+/// [`Language::Mk`] and [`Language::Sr`], see [`scholarly_ru`](crate::scholarly_ru) and others.
+/// Every letter has its own Latin string and the letters which would be read back
+/// as another letter are separated by "·", like `шч` -> "š·č", see
+/// [`scholarly_rules`](crate::scholarly_rules). So the transliteration from the Latin
+/// alphabet restores the text, except the case of the signs, which is taken from
+/// the letter next to them as in [`Gost779A`].
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{FromLatin, Language, Scholarly, ToLatin};
+/// let trasliterator = Scholarly::new(Language::Ru).unwrap();
+/// let res = trasliterator.to_latin("Щедрин, Чехов, Хрущёв, Подъячий");
+/// assert_eq!("Ščedrin, Čexov, Xruščëv, Podʺjačij", res);
+/// assert_eq!("Щедрин, Чехов, Хрущёв, Подъячий", trasliterator.from_latin(&res));
+///
+/// ```
+pub struct Scholarly {
+    translit: Transliterator,
+}
+
+impl Scholarly {
+    /// Returns `None` if the scheme has no table for the language.
+    pub fn new(lang: Language) -> Option<Scholarly> {
+        let translit = Transliterator::from_rules(scholarly::scholarly_rules(lang)?);
+
+        Some(Scholarly { translit })
+    }
+}
+
+impl_to_latin!(Scholarly);
+
+impl_from_latin!(Scholarly);
+
+/// Serbian Cyrillic and Gaj's Latin alphabet, the two official scripts of Serbian.
+/// more details:
+/// [Serbian Cyrillic alphabet](https://en.wikipedia.org/wiki/Serbian_Cyrillic_alphabet#Table)
+///
+/// The transliteration from the Latin alphabet splits the digraphs `lj`, `nj` and `dž`
+/// in the words of the exception list, see [`gaj_latin`](crate::gaj_latin),
+/// and keeps foreign words: the words with a letter which is not in Gaj's alphabet,
+/// like `w` or `x`. The policy for unmapped characters applies to the characters
+/// of foreign words outside ASCII, "Çaj" is "?aj" with `UnmappedPolicy::Replace("?")`.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{FromLatin, SerbianOfficial, ToLatin};
+/// let trasliterator = SerbianOfficial::new();
+/// assert_eq!("Ljubav i nadživeti", trasliterator.to_latin("Љубав и надживети"));
+///
+/// let res = trasliterator.from_latin("Džep, nadživeti, Windows");
+/// assert_eq!("Џеп, надживети, Windows", res);
+///
+/// ```
+pub struct SerbianOfficial {
+    translit: Transliterator,
+}
+
+impl SerbianOfficial {
+    pub fn new() -> SerbianOfficial {
+        let translit = Transliterator::new(serbian::gaj_latin());
+
+        SerbianOfficial { translit }
+    }
+
+    /// Checks that `src` is restored by the transliteration in the Latin alphabet and back
+    /// with [`FromLatin::from_latin`], which keeps foreign words.
+    /// See [`Transliterator::verify_round_trip`].
+    pub fn verify_round_trip(&self, src: &str) -> Result<(), RoundTripError> {
+        let translit = self.to_latin(src);
+        let restored = self.from_latin(&translit);
+        analysis::compare_round_trip(src, translit, restored)
+    }
+}
+
+impl Default for SerbianOfficial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl_to_latin!(SerbianOfficial);
+
+impl FromLatin for SerbianOfficial {
+    fn from_latin(&self, src: &str) -> String {
+        let mut output = String::with_capacity(src.len() * 2);
+        self.from_latin_into(src, &mut output);
+        output
+    }
+
+    fn from_latin_into(&self, src: &str, output: &mut String) {
+        serbian::from_gaj_latin(&self.translit, src, output)
+            .expect("writing to a `String` never fails");
+    }
+
+    fn from_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        serbian::from_gaj_latin(&self.translit, src, output)
+    }
+
+    fn from_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        let keep = self.translit.unmapped().is_keep();
+        if src
+            .chars()
+            .any(|c| is_latin(c) || !keep && needs_rule(c, true))
+        {
+            Cow::Owned(self.from_latin(src))
+        } else {
+            Cow::Borrowed(src)
+        }
+    }
+
+    fn from_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        stream::convert_words(reader, writer, |text| self.from_latin(text))
+    }
+}
+
+/// Kazakh Latin alphabet of 2021.
+/// more details:
+/// [Kazakh alphabets](https://en.wikipedia.org/wiki/Kazakh_alphabets#Latin_alphabet_of_2021)
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{Kazakh2021, ToLatin};
+/// let trasliterator = Kazakh2021::new();
+/// let res = trasliterator.to_latin("Қазақстан Республикасы, Шымкент, Өскемен");
+/// assert_eq!("Qazaqstan Respublikasy, Şymkent, Öskemen", res);
+///
+/// ```
+pub struct Kazakh2021 {
+    translit: Transliterator,
+}
+
+impl Kazakh2021 {
+    pub fn new() -> Kazakh2021 {
+        let translit = Transliterator::from_rules(kazakh::kazakh_2021());
+
+        Kazakh2021 { translit }
+    }
+}
+
+impl Default for Kazakh2021 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl_to_latin!(Kazakh2021);
+
+/// Uzbek Cyrillic and the official Latin alphabet of 1995.
+/// more details:
+/// [Uzbek alphabet](https://en.wikipedia.org/wiki/Uzbek_alphabet)
+///
+/// `ў` and `ғ` are written as `oʻ` and `gʻ` with "ʻ" (U+02BB), the transliteration
+/// from the Latin alphabet also accepts "'", "`", "‘" and "’" instead of it.
+/// See [`uzbek_cyrillic`](crate::uzbek_cyrillic) for how "sʼh" and `e` are read back.
+///
+/// Attention: the soft sign and `ц` are not restored, `э` only at the beginning of a word
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{FromLatin, ToLatin, UzbekOfficial};
+/// let trasliterator = UzbekOfficial::new();
+/// let res = trasliterator.to_latin("Ўзбекистон, Исҳоқ, Ғалла");
+/// assert_eq!("Oʻzbekiston, Isʼhoq, Gʻalla", res);
+///
+/// let res = trasliterator.from_latin("O'zbekiston, Isʼhoq, Yevropa");
+/// assert_eq!("Ўзбекистон, Исҳоқ, Европа", res);
+///
+/// ```
+pub struct UzbekOfficial {
+    translit: Transliterator,
+}
+
+impl UzbekOfficial {
+    pub fn new() -> UzbekOfficial {
+        let translit = Transliterator::from_rules(uzbek::uzbek_latin());
+
+        UzbekOfficial { translit }
+    }
+}
+
+impl Default for UzbekOfficial {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl FromLatin for Gost779B {
+impl_to_latin!(UzbekOfficial);
+
+impl FromLatin for UzbekOfficial {
     fn from_latin(&self, src: &str) -> String {
-        self.translit.from_latin(src)
+        let mut output = String::with_capacity(src.len() * 2);
+        self.from_latin_into(src, &mut output);
+        output
+    }
+
+    fn from_latin_into(&self, src: &str, output: &mut String) {
+        uzbek::from_uzbek_latin(self.translit.unmapped(), src, output)
+            .expect("writing to a `String` never fails");
+    }
+
+    fn from_latin_fmt(&self, src: &str, output: &mut dyn fmt::Write) -> fmt::Result {
+        uzbek::from_uzbek_latin(self.translit.unmapped(), src, output)
+    }
+
+    fn from_latin_cow<'a>(&self, src: &'a str) -> Cow<'a, str> {
+        let keep = self.translit.unmapped().is_keep();
+        if src
+            .chars()
+            .any(|c| is_latin(c) || !keep && needs_rule(c, true))
+        {
+            Cow::Owned(self.from_latin(src))
+        } else {
+            Cow::Borrowed(src)
+        }
+    }
+
+    fn from_latin_stream(&self, reader: &mut dyn Read, writer: &mut dyn Write) -> io::Result<()> {
+        stream::convert_words(reader, writer, |text| self.from_latin(text))
+    }
+}
+
+/// Mongolian national romanization MNS 5217:2012.
+/// more details:
+/// [Romanization of Mongolian](https://en.wikipedia.org/wiki/Romanization_of_Mongolian)
+///
+/// The table is [`mns_5217`](crate::mns_5217), GOST 7.79 System B for Mongolian
+/// is [`Gost779B`] with [`Language::Mn`].
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{MongolianMns5217, ToLatin};
+/// let trasliterator = MongolianMns5217::new();
+/// let res = trasliterator.to_latin("Улаанбаатар, Өлгий, Сүхбаатар, Хөвсгөл");
+/// assert_eq!("Ulaanbaatar, Ölgii, Sükhbaatar, Khövsgöl", res);
+///
+/// let trasliterator = MongolianMns5217::ascii();
+/// assert_eq!("Sukhbaatar, Khovsgol", trasliterator.to_latin("Сүхбаатар, Хөвсгөл"));
+///
+/// ```
+pub struct MongolianMns5217 {
+    translit: Transliterator,
+}
+
+impl MongolianMns5217 {
+    pub fn new() -> MongolianMns5217 {
+        let translit = Transliterator::new(mongolian::mns_5217());
+
+        MongolianMns5217 { translit }
+    }
+
+    /// The ASCII fallback, see [`mns_5217_ascii`](crate::mns_5217_ascii).
+    pub fn ascii() -> MongolianMns5217 {
+        let translit = Transliterator::new(mongolian::mns_5217_ascii());
+
+        MongolianMns5217 { translit }
+    }
+}
+
+impl Default for MongolianMns5217 {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+impl_to_latin!(MongolianMns5217);
+
+/// Official system for transliterating Ukrainian (KMU 2010).
+/// more details:
+/// [Romanization of Ukrainian](https://en.wikipedia.org/wiki/Romanization_of_Ukrainian)
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{UkrainianNational2010, ToLatin};
+/// let trasliterator = UkrainianNational2010::new();
+/// let res = trasliterator.to_latin("Згорани, Єнакієве, Знам'янка");
+/// assert_eq!("Zghorany, Yenakiieve, Znamianka", res);
+///
+/// ```
+pub struct UkrainianNational2010 {
+    translit: Transliterator,
+}
+
+impl UkrainianNational2010 {
+    pub fn new() -> UkrainianNational2010 {
+        let translit = Transliterator::from_rules(ukrainian::national_2010());
+
+        UkrainianNational2010 { translit }
+    }
+}
+
+impl Default for UkrainianNational2010 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl_to_latin!(UkrainianNational2010);
+
+impl_from_latin_candidates!(UkrainianNational2010, LetterModel::ukrainian());
+
 /// Cyrillic Russian transliteration table.
 /// implementation Passport (2013), ICAO.
 /// more details:
@@ -187,12 +1394,126 @@ impl Passport2013 {
     }
 }
 
-impl ToLatin for Passport2013 {
-    fn to_latin(&self, src: &str) -> String {
-        self.translit.to_latin(src)
+impl Default for Passport2013 {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+impl_to_latin!(Passport2013);
+
+impl_from_latin_candidates!(Passport2013, LetterModel::russian());
+
+/// BGN/PCGN romanization, used by the mapping agencies of the United States
+/// and the United Kingdom for geographic names.
+/// more details:
+/// [BGN/PCGN romanization](https://en.wikipedia.org/wiki/BGN/PCGN_romanization)
+///
+/// Systems for [`Language::Ru`], [`Language::Ua`], [`Language::By`] and [`Language::Bg`],
+/// see [`bgn_pcgn_ru`](crate::bgn_pcgn_ru) and others for the rules.
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{BgnPcgn, Language, ToLatin};
+/// let trasliterator = BgnPcgn::new(Language::Ru).unwrap();
+/// let res = trasliterator.to_latin("Ельцин, Королёв, Подъезд");
+/// assert_eq!("Yel’tsin, Korolëv, Pod”yezd", res);
+///
+/// let trasliterator = BgnPcgn::with_separator(Language::Ru).unwrap();
+/// assert_eq!("Det·skiy", trasliterator.to_latin("Детский"));
+///
+/// ```
+pub struct BgnPcgn {
+    translit: Transliterator,
+}
+
+impl BgnPcgn {
+    /// Returns `None` if the BGN/PCGN has no system for the language.
+    pub fn new(lang: Language) -> Option<BgnPcgn> {
+        Self::with_options(lang, false)
+    }
+
+    /// The letters which would be read as another letter are separated by "·",
+    /// like "t·s" for `тс`, which is not `ц`.
+    pub fn with_separator(lang: Language) -> Option<BgnPcgn> {
+        Self::with_options(lang, true)
+    }
+
+    fn with_options(lang: Language, separator: bool) -> Option<BgnPcgn> {
+        let rules = match lang {
+            Language::Ru => bgn_pcgn::bgn_pcgn_ru(separator),
+            Language::Ua => bgn_pcgn::bgn_pcgn_ua(separator),
+            Language::By => bgn_pcgn::bgn_pcgn_by(separator),
+            Language::Bg => bgn_pcgn::bgn_pcgn_bg(),
+            _ => return None,
+        };
+        let translit = Transliterator::from_rules(rules);
+
+        Some(BgnPcgn { translit })
+    }
+}
+
+impl_to_latin!(BgnPcgn);
+
+/// ALA-LC romanization of the Library of Congress, required by the library catalogues
+/// (MARC records).
+/// more details:
+/// [ALA-LC romanization](https://www.loc.gov/catdir/cpso/roman.html)
+///
+/// Tables for [`Language::Ru`], [`Language::Ua`] and [`Language::By`],
+/// see [`ala_lc_ru`](crate::ala_lc_ru) and others.
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{AlaLc, Language, ToLatin};
+/// let trasliterator = AlaLc::new(Language::Ru).unwrap();
+/// let res = trasliterator.to_latin("Юрий Цветаев, объём");
+/// assert_eq!("I\u{361}uriĭ T\u{361}svetaev, obʺëm", res);
+///
+/// let trasliterator = AlaLc::ascii(Language::Ru).unwrap();
+/// assert_eq!("Iurii Tsvetaev, ob\"em", trasliterator.to_latin("Юрий Цветаев, объём"));
+///
+/// ```
+pub struct AlaLc {
+    translit: Transliterator,
+}
+
+impl AlaLc {
+    /// Returns `None` if the ALA-LC has no table for the language.
+    pub fn new(lang: Language) -> Option<AlaLc> {
+        Self::table(lang).map(|table| AlaLc {
+            translit: Transliterator::new(table),
+        })
+    }
+
+    /// The ASCII fallback without the diacritics and the ties,
+    /// see [`ala_lc_ascii`](crate::ala_lc_ascii).
+    pub fn ascii(lang: Language) -> Option<AlaLc> {
+        Self::table(lang).map(|table| AlaLc {
+            translit: Transliterator::new(ala_lc::ala_lc_ascii(table)),
+        })
+    }
+
+    fn table(lang: Language) -> Option<CharsMapping> {
+        match lang {
+            Language::Ru => Some(ala_lc::ala_lc_ru()),
+            Language::Ua => Some(ala_lc::ala_lc_ua()),
+            Language::By => Some(ala_lc::ala_lc_by()),
+            _ => None,
+        }
+    }
+}
+
+impl_to_latin!(AlaLc);
+
 /// Cyrillic Russian transliteration table for driver license.
 ///
 pub struct OrderN995 {
@@ -207,12 +1528,16 @@ impl OrderN995 {
     }
 }
 
-impl ToLatin for OrderN995 {
-    fn to_latin(&self, src: &str) -> String {
-        self.translit.to_latin(src)
+impl Default for OrderN995 {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+impl_to_latin!(OrderN995);
+
+impl_from_latin_candidates!(OrderN995, LetterModel::russian());
+
 /// Official system for transliterating Bulgarian
 ///
 /// more details:
@@ -242,12 +1567,16 @@ impl BulgarianOfficial {
     }
 }
 
-impl ToLatin for BulgarianOfficial {
-    fn to_latin(&self, src: &str) -> String {
-        self.translit.to_latin(src)
+impl Default for BulgarianOfficial {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+impl_to_latin!(BulgarianOfficial);
+
+impl_from_latin_candidates!(BulgarianOfficial, LetterModel::bulgarian());
+
 /// Official system for transliterating Macedonian
 ///
 /// more details:
@@ -277,8 +1606,12 @@ impl MacedonianOfficial {
     }
 }
 
-impl ToLatin for MacedonianOfficial {
-    fn to_latin(&self, src: &str) -> String {
-        self.translit.to_latin(src)
+impl Default for MacedonianOfficial {
+    fn default() -> Self {
+        Self::new()
     }
 }
+
+impl_to_latin!(MacedonianOfficial);
+
+impl_from_latin_candidates!(MacedonianOfficial, LetterModel::macedonian());
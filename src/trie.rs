@@ -0,0 +1,86 @@
+/// Prefix tree over the keys of a transliteration table.
+///
+/// Each node keeps its children sorted by character, so a lookup costs
+/// one binary search per input character.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Trie {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: Vec<(char, usize)>,
+    value: Option<usize>,
+}
+
+impl Trie {
+    pub(crate) fn new() -> Self {
+        Trie {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Returns the value of `key`, the key is inserted with `value` if it is absent.
+    pub(crate) fn get_or_insert(&mut self, key: &str, value: usize) -> usize {
+        let mut node = 0;
+
+        for c in key.chars() {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by(|&(ch, _)| ch.cmp(&c))
+            {
+                Ok(pos) => self.nodes[node].children[pos].1,
+                Err(pos) => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(pos, (c, next));
+                    next
+                }
+            };
+        }
+
+        *self.nodes[node].value.get_or_insert(value)
+    }
+
+    /// Iterates over the byte lengths and the values of all keys that are prefixes of `text`,
+    /// from the shortest key to the longest one.
+    pub(crate) fn prefixes<'a>(&'a self, text: &'a str) -> Prefixes<'a> {
+        Prefixes {
+            trie: self,
+            chars: text.char_indices(),
+            node: Some(0),
+        }
+    }
+
+    fn child(&self, node: usize, c: char) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        children
+            .binary_search_by(|&(ch, _)| ch.cmp(&c))
+            .ok()
+            .map(|pos| children[pos].1)
+    }
+}
+
+/// Iterator over the keys that are prefixes of a text, see [`Trie::prefixes`].
+pub(crate) struct Prefixes<'a> {
+    trie: &'a Trie,
+    chars: std::str::CharIndices<'a>,
+    node: Option<usize>,
+}
+
+impl Iterator for Prefixes<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.node {
+            let (pos, c) = self.chars.next()?;
+            self.node = self.trie.child(node, c);
+
+            if let Some(value) = self.node.and_then(|next| self.trie.nodes[next].value) {
+                return Some((pos + c.len_utf8(), value));
+            }
+        }
+
+        None
+    }
+}
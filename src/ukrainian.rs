@@ -0,0 +1,113 @@
+use crate::{Condition, Rule};
+
+/// The letters of the Ukrainian alphabet.
+const LETTERS: &str = "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя";
+
+/// Official system for transliterating Ukrainian,
+/// Resolution of the Cabinet of Ministers of Ukraine No. 55 of 27 January 2010.
+///
+/// more details:
+/// [Romanization of Ukrainian #National](https://en.wikipedia.org/wiki/Romanization_of_Ukrainian#Tables_of_romanization_systems)
+///
+/// The letters `є`, `ї`, `й`, `ю`, `я` are transliterated differently at the beginning of a word,
+/// `зг` becomes `zgh`, the soft sign and the apostrophe between letters are dropped.
+///
+/// Attention: Converting back from romanized cyrillic to cyrillic is ambiguous, thus not supported
+pub fn national_2010() -> Vec<Rule> {
+    let word_start = Condition::WordBoundary;
+
+    let mut rules = vec![
+        Rule::new("Є", "Ye").before(word_start),
+        Rule::new("Є", "Ie"),
+        Rule::new("Ї", "Yi").before(word_start),
+        Rule::new("Ї", "I"),
+        Rule::new("Й", "Y").before(word_start),
+        Rule::new("Й", "I"),
+        Rule::new("Ю", "Yu").before(word_start),
+        Rule::new("Ю", "Iu"),
+        Rule::new("Я", "Ya").before(word_start),
+        Rule::new("Я", "Ia"),
+        Rule::new("є", "ye").before(word_start),
+        Rule::new("є", "ie"),
+        Rule::new("ї", "yi").before(word_start),
+        Rule::new("ї", "i"),
+        Rule::new("й", "y").before(word_start),
+        Rule::new("й", "i"),
+        Rule::new("ю", "yu").before(word_start),
+        Rule::new("ю", "iu"),
+        Rule::new("я", "ya").before(word_start),
+        Rule::new("я", "ia"),
+    ];
+
+    rules.extend(
+        [
+            ("зг", "zgh"),
+            ("А", "A"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "H"),
+            ("Ґ", "G"),
+            ("Д", "D"),
+            ("Е", "E"),
+            ("Ж", "Zh"),
+            ("З", "Z"),
+            ("И", "Y"),
+            ("І", "I"),
+            ("К", "K"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("О", "O"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ф", "F"),
+            ("Х", "Kh"),
+            ("Ц", "Ts"),
+            ("Ч", "Ch"),
+            ("Ш", "Sh"),
+            ("Щ", "Shch"),
+            ("Ь", ""),
+            ("а", "a"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "h"),
+            ("ґ", "g"),
+            ("д", "d"),
+            ("е", "e"),
+            ("ж", "zh"),
+            ("з", "z"),
+            ("и", "y"),
+            ("і", "i"),
+            ("к", "k"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("о", "o"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ф", "f"),
+            ("х", "kh"),
+            ("ц", "ts"),
+            ("ч", "ch"),
+            ("ш", "sh"),
+            ("щ", "shch"),
+            ("ь", ""),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    // the apostrophe between letters, other apostrophes are quotation marks
+    let letter = Condition::OneOf(LETTERS);
+    for apostrophe in ["'", "’", "ʼ"] {
+        rules.push(Rule::new(apostrophe, "").before(letter).after(letter));
+    }
+
+    rules
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// What to do with a character which has no rule in the table.
+///
+/// These are all characters outside ASCII and, in the transliteration
+/// from the Latin alphabet, the Latin letters. ASCII digits, spaces
+/// and punctuation without a rule are always kept, so with a policy
+/// other than `Keep` the transliteration in the Latin alphabet is ASCII only.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use translit::{Passport2013, ToLatin, UnmappedPolicy};
+/// let trasliterator = Passport2013::new().with_unmapped(UnmappedPolicy::Remove);
+/// assert_eq!("Kiv, ", trasliterator.to_latin("Київ, «α»"));
+///
+/// let trasliterator = Passport2013::new().with_unmapped(UnmappedPolicy::Escape);
+/// assert_eq!("Ki\\u{457}v \\u{2014}", trasliterator.to_latin("Київ —"));
+///
+/// ```
+#[derive(Clone, Default)]
+pub enum UnmappedPolicy {
+    /// Copy the character to the output as is.
+    #[default]
+    Keep,
+    /// Drop the character.
+    Remove,
+    /// Replace the character with the string.
+    Replace(String),
+    /// Replace the character with its code point in the form `\u{...}`.
+    Escape,
+    /// Replace the character with the result of the function.
+    Custom(Arc<dyn Fn(char) -> String + Send + Sync>),
+}
+
+impl UnmappedPolicy {
+    /// Creates the policy which replaces a character with the result of `f`.
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(char) -> String + Send + Sync + 'static,
+    {
+        UnmappedPolicy::Custom(Arc::new(f))
+    }
+
+    pub(crate) fn is_keep(&self) -> bool {
+        matches!(self, UnmappedPolicy::Keep)
+    }
+
+    pub(crate) fn write<W: fmt::Write + ?Sized>(&self, c: char, output: &mut W) -> fmt::Result {
+        match self {
+            UnmappedPolicy::Keep => output.write_char(c),
+            UnmappedPolicy::Remove => Ok(()),
+            UnmappedPolicy::Replace(replacement) => output.write_str(replacement),
+            UnmappedPolicy::Escape => write!(output, "{}", c.escape_unicode()),
+            UnmappedPolicy::Custom(f) => output.write_str(&f(c)),
+        }
+    }
+}
+
+impl fmt::Debug for UnmappedPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnmappedPolicy::Keep => f.write_str("Keep"),
+            UnmappedPolicy::Remove => f.write_str("Remove"),
+            UnmappedPolicy::Replace(replacement) => {
+                f.debug_tuple("Replace").field(replacement).finish()
+            }
+            UnmappedPolicy::Escape => f.write_str("Escape"),
+            UnmappedPolicy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
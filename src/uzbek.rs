@@ -0,0 +1,257 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::script::needs_rule;
+use crate::transliterator::{Step, Transliterator};
+use crate::unmapped::UnmappedPolicy;
+use crate::{Condition, Rule};
+
+/// The vowels of Uzbek Cyrillic and the signs, `е` after them is `ye`.
+const VOWELS_AND_SIGNS: &str = "аеёиоуэюяўъь";
+
+/// The Latin letters, an apostrophe between them is the sign `ъ`.
+const LATIN_LETTERS: &str = "abdefghijklmnopqrstuvxyz";
+
+/// Uzbek Cyrillic to the official Latin alphabet (1995).
+///
+/// more details:
+/// [Uzbek alphabet](https://en.wikipedia.org/wiki/Uzbek_alphabet)
+///
+/// `ў` and `ғ` are written with the modifier letter turned comma "ʻ" (U+02BB),
+/// `ъ` with the modifier letter apostrophe "ʼ" (U+02BC). The letter `е` is `ye`
+/// at the beginning of a word and after vowels, `ц` is `ts` after vowels and `s` otherwise,
+/// `с` before `ҳ` is `sʼ` to be read apart from `ш`: "Isʼhoq". The soft sign is dropped.
+pub fn uzbek_latin() -> Vec<Rule> {
+    let word_start = Condition::WordBoundary;
+    let vowel = Condition::OneOf(VOWELS_AND_SIGNS);
+    let before_h = Condition::OneOf("ҳ");
+
+    let mut rules = vec![
+        Rule::new("Е", "Ye").before(word_start),
+        Rule::new("Е", "Ye").before(vowel),
+        Rule::new("Е", "E"),
+        Rule::new("е", "ye").before(word_start),
+        Rule::new("е", "ye").before(vowel),
+        Rule::new("е", "e"),
+        Rule::new("Ц", "Ts").before(vowel),
+        Rule::new("Ц", "S"),
+        Rule::new("ц", "ts").before(vowel),
+        Rule::new("ц", "s"),
+        Rule::new("С", "Sʼ").after(before_h),
+        Rule::new("с", "sʼ").after(before_h),
+    ];
+
+    rules.extend(
+        [
+            ("А", "A"),
+            ("Б", "B"),
+            ("В", "V"),
+            ("Г", "G"),
+            ("Д", "D"),
+            ("Ё", "Yo"),
+            ("Ж", "J"),
+            ("З", "Z"),
+            ("И", "I"),
+            ("Й", "Y"),
+            ("К", "K"),
+            ("Л", "L"),
+            ("М", "M"),
+            ("Н", "N"),
+            ("О", "O"),
+            ("П", "P"),
+            ("Р", "R"),
+            ("С", "S"),
+            ("Т", "T"),
+            ("У", "U"),
+            ("Ф", "F"),
+            ("Х", "X"),
+            ("Ч", "Ch"),
+            ("Ш", "Sh"),
+            ("ъ", "ʼ"),
+            ("Ъ", "ʼ"),
+            ("Ь", ""),
+            ("Э", "E"),
+            ("Ю", "Yu"),
+            ("Я", "Ya"),
+            ("Ў", "Oʻ"),
+            ("Қ", "Q"),
+            ("Ғ", "Gʻ"),
+            ("Ҳ", "H"),
+            ("а", "a"),
+            ("б", "b"),
+            ("в", "v"),
+            ("г", "g"),
+            ("д", "d"),
+            ("ё", "yo"),
+            ("ж", "j"),
+            ("з", "z"),
+            ("и", "i"),
+            ("й", "y"),
+            ("к", "k"),
+            ("л", "l"),
+            ("м", "m"),
+            ("н", "n"),
+            ("о", "o"),
+            ("п", "p"),
+            ("р", "r"),
+            ("с", "s"),
+            ("т", "t"),
+            ("у", "u"),
+            ("ф", "f"),
+            ("х", "x"),
+            ("ч", "ch"),
+            ("ш", "sh"),
+            ("ь", ""),
+            ("э", "e"),
+            ("ю", "yu"),
+            ("я", "ya"),
+            ("ў", "oʻ"),
+            ("қ", "q"),
+            ("ғ", "gʻ"),
+            ("ҳ", "h"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
+
+/// The official Uzbek Latin alphabet (1995) to Cyrillic, the sources of the rules are Latin.
+///
+/// Besides "ʻ" the letters `oʻ` and `gʻ` are accepted with "'", "`", "‘" and "’".
+/// `e` is `э` at the beginning of a word and `е` elsewhere, `ye` is `е`.
+/// "sʼh" is `сҳ`, an apostrophe between other letters is `ъ`.
+///
+/// The soft sign and `ц` are not restored: "ts" is read as `тс`, "s" as `с`.
+pub fn uzbek_cyrillic() -> Vec<Rule> {
+    let word_start = Condition::WordBoundary;
+    let letter = Condition::OneOf(LATIN_LETTERS);
+
+    let mut rules: Vec<Rule> = [
+        ("yoʻ", "йў"),
+        ("yo'", "йў"),
+        ("yo`", "йў"),
+        ("yo‘", "йў"),
+        ("yo’", "йў"),
+        ("oʻ", "ў"),
+        ("o'", "ў"),
+        ("o`", "ў"),
+        ("o‘", "ў"),
+        ("o’", "ў"),
+        ("gʻ", "ғ"),
+        ("g'", "ғ"),
+        ("g`", "ғ"),
+        ("g‘", "ғ"),
+        ("g’", "ғ"),
+        ("sʼh", "сҳ"),
+        ("s'h", "сҳ"),
+        ("s`h", "сҳ"),
+        ("s’h", "сҳ"),
+        ("ʼ", "ъ"),
+    ]
+    .iter()
+    .map(|&rule| Rule::from(rule))
+    .collect();
+
+    for apostrophe in ["'", "`", "’"] {
+        rules.push(Rule::new(apostrophe, "ъ").before(letter).after(letter));
+    }
+
+    rules.extend([
+        Rule::new("E", "Э").before(word_start),
+        Rule::new("E", "Е"),
+        Rule::new("e", "э").before(word_start),
+        Rule::new("e", "е"),
+    ]);
+
+    rules.extend(
+        [
+            ("ye", "е"),
+            ("yo", "ё"),
+            ("yu", "ю"),
+            ("ya", "я"),
+            ("sh", "ш"),
+            ("ch", "ч"),
+            ("A", "А"),
+            ("B", "Б"),
+            ("D", "Д"),
+            ("F", "Ф"),
+            ("G", "Г"),
+            ("H", "Ҳ"),
+            ("I", "И"),
+            ("J", "Ж"),
+            ("K", "К"),
+            ("L", "Л"),
+            ("M", "М"),
+            ("N", "Н"),
+            ("O", "О"),
+            ("P", "П"),
+            ("Q", "Қ"),
+            ("R", "Р"),
+            ("S", "С"),
+            ("T", "Т"),
+            ("U", "У"),
+            ("V", "В"),
+            ("X", "Х"),
+            ("Y", "Й"),
+            ("Z", "З"),
+            ("a", "а"),
+            ("b", "б"),
+            ("d", "д"),
+            ("f", "ф"),
+            ("g", "г"),
+            ("h", "ҳ"),
+            ("i", "и"),
+            ("j", "ж"),
+            ("k", "к"),
+            ("l", "л"),
+            ("m", "м"),
+            ("n", "н"),
+            ("o", "о"),
+            ("p", "п"),
+            ("q", "қ"),
+            ("r", "р"),
+            ("s", "с"),
+            ("t", "т"),
+            ("u", "у"),
+            ("v", "в"),
+            ("x", "х"),
+            ("y", "й"),
+            ("z", "з"),
+        ]
+        .iter()
+        .map(|&rule| Rule::from(rule)),
+    );
+
+    rules
+}
+
+/// The transliterator of [`uzbek_cyrillic`], built once.
+fn from_latin() -> &'static Transliterator {
+    static TRANSLIT: OnceLock<Transliterator> = OnceLock::new();
+    TRANSLIT.get_or_init(|| Transliterator::from_rules(uzbek_cyrillic()))
+}
+
+/// Transliterates the Latin text with [`uzbek_cyrillic`], the characters without a rule
+/// are written by the policy as in the transliteration from the Latin alphabet.
+pub(crate) fn from_uzbek_latin<W: fmt::Write + ?Sized>(
+    policy: &UnmappedPolicy,
+    src: &str,
+    output: &mut W,
+) -> fmt::Result {
+    let translit = from_latin();
+    let mut pos = 0;
+    while pos < src.len() {
+        let step = translit.step(src, pos, false, output)?;
+        if let Step::Char(c) = step {
+            if needs_rule(c, true) {
+                policy.write(c, output)?;
+            } else {
+                output.write_char(c)?;
+            }
+        }
+        pos += step.len();
+    }
+    Ok(())
+}